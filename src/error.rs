@@ -1,5 +1,7 @@
 use std::num::ParseIntError;
 
+use crate::solution::Stage;
+
 /// Convenience type of `Result<T, AocError>`
 pub type Result<T> = std::result::Result<T, AocError>;
 
@@ -18,6 +20,17 @@ pub enum AocError {
     /// Used to indicate a `Location` could not be parsed
     ParseLocationError(String),
 
+    /// Used to indicate a `TrySolver` failed to parse its input or to produce
+    /// the solution for one of its parts
+    SolverError {
+        label: String,
+        stage: Stage,
+        reason: String,
+    },
+
+    /// Used to indicate the runner was given invalid arguments
+    UsageError(String),
+
     // Generic Errors below
     /// Represents all other cases of IO errors
     IOError(std::io::Error),
@@ -28,6 +41,9 @@ pub enum AocError {
     /// Represents all other cases of ParseIntErrors
     ParseIntError(ParseIntError),
 
+    /// Represents all other cases of serde_json Errors
+    SerdeJsonError(serde_json::Error),
+
     /// Represents all other cases VarErrors
     VarError(std::env::VarError),
 }
@@ -39,11 +55,14 @@ impl std::error::Error for AocError {
             AocError::InputMissing(_) => None,
             AocError::ParseDirectionError(_) => None,
             AocError::ParseLocationError(_) => None,
+            AocError::SolverError { .. } => None,
+            AocError::UsageError(_) => None,
 
             // Generic
             AocError::IOError(ref err) => Some(err),
             AocError::OsStringErr(_) => None,
             AocError::ParseIntError(ref err) => Some(err),
+            AocError::SerdeJsonError(ref err) => Some(err),
             AocError::VarError(ref err) => Some(err),
         }
     }
@@ -62,11 +81,18 @@ impl std::fmt::Display for AocError {
                 write!(f, "failed to parse Direction: {}", msg)
             }
             AocError::ParseLocationError(ref msg) => write!(f, "failed to parse Location: {}", msg),
+            AocError::SolverError {
+                ref label,
+                stage,
+                ref reason,
+            } => write!(f, "{} failed during {}: {}", label, stage, reason),
+            AocError::UsageError(ref msg) => write!(f, "invalid usage: {}", msg),
 
             // Generic
             AocError::IOError(ref err) => err.fmt(f),
             AocError::OsStringErr(ref err) => write!(f, "OsString error: {:?}", err),
             AocError::ParseIntError(ref err) => err.fmt(f),
+            AocError::SerdeJsonError(ref err) => err.fmt(f),
             AocError::VarError(ref err) => err.fmt(f),
         }
     }
@@ -84,6 +110,12 @@ impl From<ParseIntError> for AocError {
     }
 }
 
+impl From<serde_json::Error> for AocError {
+    fn from(err: serde_json::Error) -> AocError {
+        AocError::SerdeJsonError(err)
+    }
+}
+
 impl From<std::ffi::OsString> for AocError {
     fn from(err: std::ffi::OsString) -> AocError {
         AocError::OsStringErr(err)
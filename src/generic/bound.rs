@@ -1,9 +1,14 @@
-use std::fmt::{self, Display};
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::fmt::{self, Debug, Display};
 use std::hash::Hash;
+use std::iter::FromIterator;
 
-use num::{Bounded, Num};
+use itertools::Itertools;
+use num::{Bounded, Num, NumCast};
 
-use super::Location;
+use super::grid::GridLike;
+use super::{Grid, Location};
 
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
 pub struct Bound2D<T>
@@ -67,6 +72,517 @@ where
     pub fn area(&self) -> T {
         self.width() * self.height()
     }
+
+    /// Whether or not this bound contains no points, such as the initial
+    /// [`minmax`](Bound2D::minmax) state.
+    pub fn is_empty(&self) -> bool {
+        self.min_x > self.max_x || self.min_y > self.max_y
+    }
+
+    /// Grow this bound, if necessary, to include the given point.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::Bound2D;
+    ///
+    /// let mut b = Bound2D::minmax();
+    /// b.extend_with(3, -2);
+    /// b.extend_with(-1, 5);
+    ///
+    /// assert_eq!(b, Bound2D::new(-1, 3, -2, 5));
+    /// ```
+    pub fn extend_with(&mut self, x: T, y: T) {
+        self.min_x = min(self.min_x, x);
+        self.max_x = max(self.max_x, x);
+        self.min_y = min(self.min_y, y);
+        self.max_y = max(self.max_y, y);
+    }
+
+    /// Returns the smallest bound containing both this bound and `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(
+            min(self.min_x, other.min_x),
+            max(self.max_x, other.max_x),
+            min(self.min_y, other.min_y),
+            max(self.max_y, other.max_y),
+        )
+    }
+
+    /// Returns the bound covered by both this bound and `other`, if any.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::Bound2D;
+    ///
+    /// let a = Bound2D::new(0, 5, 0, 5);
+    /// let b = Bound2D::new(3, 8, -2, 1);
+    ///
+    /// assert_eq!(a.intersection(&b), Some(Bound2D::new(3, 5, 0, 1)));
+    /// assert_eq!(a.intersection(&Bound2D::new(6, 8, 0, 5)), None);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let res = Self::new(
+            max(self.min_x, other.min_x),
+            min(self.max_x, other.max_x),
+            max(self.min_y, other.min_y),
+            min(self.max_y, other.max_y),
+        );
+
+        if res.is_empty() {
+            None
+        } else {
+            Some(res)
+        }
+    }
+
+    /// Whether or not this bound shares at least one point with `other`.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Whether or not `other` lies entirely within this bound.
+    pub fn contains_bound(&self, other: &Self) -> bool {
+        self.contains(other.min_x, other.min_y) && self.contains(other.max_x, other.max_y)
+    }
+
+    /// Returns the disjoint pieces of this bound not covered by `other`. There
+    /// will be at most four pieces: the full-width strips before and after
+    /// `other` along the y axis, and the pieces on either side of `other`
+    /// along the x axis.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::Bound2D;
+    ///
+    /// let a = Bound2D::new(0, 9, 0, 9);
+    /// let hole = Bound2D::new(3, 5, 3, 5);
+    /// let pieces = a.subtract(&hole);
+    ///
+    /// assert_eq!(pieces.len(), 4);
+    /// assert_eq!(pieces.iter().map(|p| p.area()).sum::<i32>(), 100 - 9);
+    /// assert_eq!(a.subtract(&a), vec![]);
+    /// ```
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let i = match self.intersection(other) {
+            Some(i) => i,
+            None => return vec![*self],
+        };
+
+        // each piece is only produced when `i` stops short of that side of
+        // `self`, so stepping past `i` never leaves the range of `T`
+        let mut pieces = Vec::with_capacity(4);
+        if i.min_y > self.min_y {
+            pieces.push(Self::new(
+                self.min_x,
+                self.max_x,
+                self.min_y,
+                i.min_y - T::one(),
+            ));
+        }
+        if i.max_y < self.max_y {
+            pieces.push(Self::new(
+                self.min_x,
+                self.max_x,
+                i.max_y + T::one(),
+                self.max_y,
+            ));
+        }
+        if i.min_x > self.min_x {
+            pieces.push(Self::new(self.min_x, i.min_x - T::one(), i.min_y, i.max_y));
+        }
+        if i.max_x < self.max_x {
+            pieces.push(Self::new(i.max_x + T::one(), self.max_x, i.min_y, i.max_y));
+        }
+
+        pieces
+    }
+
+    /// Returns the total area covered by the given bounds, counting regions
+    /// covered by more than one bound only once.
+    ///
+    /// This sweeps across the distinct x coordinates, so it runs in roughly
+    /// `O(n^2 log n)` for `n` bounds regardless of their size.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::Bound2D;
+    ///
+    /// let claims = vec![
+    ///     Bound2D::new(1, 4, 3, 6),
+    ///     Bound2D::new(3, 6, 1, 4),
+    ///     Bound2D::new(5, 6, 5, 6),
+    /// ];
+    /// assert_eq!(Bound2D::union_area(&claims), 16 + 16 + 4 - 4);
+    /// ```
+    pub fn union_area(bounds: &[Self]) -> T {
+        let bounds: Vec<_> = bounds.iter().filter(|b| !b.is_empty()).collect();
+        let xs = sorted_edges(bounds.iter().map(|b| (b.min_x, b.max_x)));
+
+        slabs(&xs).fold(T::zero(), |acc, (x, next)| {
+            let covered = covered_length(
+                bounds
+                    .iter()
+                    .filter(|b| b.min_x <= x && b.max_x >= x)
+                    .map(|b| (b.min_y, b.max_y)),
+            );
+            if covered == T::zero() {
+                acc
+            } else {
+                acc + covered * slab_width(x, next)
+            }
+        })
+    }
+
+    /// Returns a copy of this bound grown by `margin` on every side. Values
+    /// that would exceed the limits of `T` are clamped to those limits. A
+    /// negative margin shrinks the bound instead, which may leave it empty.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::Bound2D;
+    ///
+    /// let b: Bound2D<usize> = Bound2D::new(1, 4, 0, 3);
+    /// assert_eq!(b.expand(2), Bound2D::new(0, 6, 0, 5));
+    ///
+    /// let b: Bound2D<i32> = Bound2D::new(1, 4, 0, 3);
+    /// assert_eq!(b.expand(-1), Bound2D::new(2, 3, 1, 2));
+    /// ```
+    pub fn expand(&self, margin: T) -> Self {
+        Self::new(
+            saturating_sub(self.min_x, margin),
+            saturating_add(self.max_x, margin),
+            saturating_sub(self.min_y, margin),
+            saturating_add(self.max_y, margin),
+        )
+    }
+
+    /// Returns a copy of this bound shrunk by `margin` on every side, or `None`
+    /// if nothing would remain. A negative margin grows the bound instead, in
+    /// the same way as [`expand`](Bound2D::expand).
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::Bound2D;
+    ///
+    /// let b: Bound2D<usize> = Bound2D::new(1, 4, 0, 3);
+    /// assert_eq!(b.shrink(1), Some(Bound2D::new(2, 3, 1, 2)));
+    /// assert_eq!(b.shrink(2), None);
+    ///
+    /// let b: Bound2D<i32> = Bound2D::new(1, 4, 0, 3);
+    /// assert_eq!(b.shrink(-1), Some(Bound2D::new(0, 5, -1, 4)));
+    /// ```
+    pub fn shrink(&self, margin: T) -> Option<Self> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let shrunk = Self::new(
+            saturating_add(self.min_x, margin),
+            saturating_sub(self.max_x, margin),
+            saturating_add(self.min_y, margin),
+            saturating_sub(self.max_y, margin),
+        );
+
+        if shrunk.is_empty() {
+            None
+        } else {
+            Some(shrunk)
+        }
+    }
+
+    /// Returns the `(x, y)` corner with the smallest coordinates.
+    pub fn min_corner(&self) -> (T, T) {
+        (self.min_x, self.min_y)
+    }
+
+    /// Returns the `(x, y)` corner with the largest coordinates.
+    pub fn max_corner(&self) -> (T, T) {
+        (self.max_x, self.max_y)
+    }
+
+    /// Yields every `(x, y)` point within this bound in row-major order, that
+    /// is, all points with `min_y` from `min_x` to `max_x`, then all points
+    /// with `min_y + 1`, and so on.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::Bound2D;
+    ///
+    /// let b = Bound2D::new(-1, 0, 5, 6);
+    /// let points: Vec<_> = b.points().collect();
+    ///
+    /// assert_eq!(points, vec![(-1, 5), (0, 5), (-1, 6), (0, 6)]);
+    /// ```
+    pub fn points(&self) -> impl Iterator<Item = (T, T)> {
+        let b = *self;
+        inclusive(b.min_y, b.max_y)
+            .flat_map(move |y| inclusive(b.min_x, b.max_x).map(move |x| (x, y)))
+    }
+
+    /// Renders every point within this bound as the char returned by `f`, one
+    /// line per row. The output matches the `Display` of a [Grid](super::Grid)
+    /// of the same chars.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::Bound2D;
+    ///
+    /// let b = Bound2D::new(0, 2, 0, 1);
+    /// assert_eq!(b.render(|x, y| if x == y { '#' } else { '.' }), "#..\n.#.");
+    /// ```
+    pub fn render<F>(&self, f: F) -> String
+    where
+        F: Fn(T, T) -> char,
+    {
+        let b = *self;
+        inclusive(b.min_y, b.max_y)
+            .map(|y| {
+                inclusive(b.min_x, b.max_x)
+                    .map(|x| f(x, y))
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+
+    /// Returns all four `(x, y)` corners, starting from
+    /// [`min_corner`](Bound2D::min_corner) and moving along the x axis first.
+    pub fn corners(&self) -> [(T, T); 4] {
+        [
+            (self.min_x, self.min_y),
+            (self.max_x, self.min_y),
+            (self.max_x, self.max_y),
+            (self.min_x, self.max_y),
+        ]
+    }
+}
+
+impl<T> Bound2D<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Default + Hash + Eq,
+{
+    /// Renders a set of `(x, y)` points within their bound, drawing points in
+    /// the set as `#` and all others as `.`. Useful for puzzles where the
+    /// answer is spelled out in letters.
+    ///
+    /// Example
+    /// ```
+    /// use std::collections::HashSet;
+    /// use aoc_helpers::generic::Bound2D;
+    ///
+    /// let points: HashSet<(i32, i32)> = vec![(1, 1), (2, 2), (1, 3)].into_iter().collect();
+    /// assert_eq!(Bound2D::render_set(&points), "#.\n.#\n#.");
+    /// ```
+    pub fn render_set(points: &HashSet<(T, T)>) -> String {
+        let bound: Self = points.iter().copied().collect();
+        bound.render(|x, y| if points.contains(&(x, y)) { '#' } else { '.' })
+    }
+
+    /// Renders a map of `(x, y)` points to chars within their bound, drawing
+    /// `default` for any point not in the map.
+    ///
+    /// Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use aoc_helpers::generic::Bound2D;
+    ///
+    /// let mut points: HashMap<(i32, i32), char> = HashMap::new();
+    /// points.insert((-1, 0), 'S');
+    /// points.insert((1, 1), 'E');
+    /// assert_eq!(Bound2D::render_map(&points, ' '), "S  \n  E");
+    /// ```
+    pub fn render_map(points: &HashMap<(T, T), char>, default: char) -> String {
+        let bound: Self = points.keys().copied().collect();
+        bound.render(|x, y| points.get(&(x, y)).copied().unwrap_or(default))
+    }
+}
+
+/// Builds the smallest bound containing every `(x, y)` point.
+///
+/// Example
+/// ```
+/// use aoc_helpers::generic::Bound2D;
+///
+/// let b: Bound2D<i64> = vec![(1, 2), (-3, 4), (0, -7)].into_iter().collect();
+/// assert_eq!(b, Bound2D::new(-3, 1, -7, 4));
+/// ```
+impl<T> FromIterator<(T, T)> for Bound2D<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Default + Hash,
+{
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(iter: I) -> Self {
+        let mut bound = Self::minmax();
+        for (x, y) in iter {
+            bound.extend_with(x, y);
+        }
+        bound
+    }
+}
+
+/// Returns the sorted, distinct half-open edges of the given inclusive ranges,
+/// that is, every `start` and every `end + 1`. An `end + 1` beyond the largest
+/// value of `T` is represented by `None`, which sorts last.
+fn sorted_edges<T, I>(ranges: I) -> Vec<Option<T>>
+where
+    T: Num + Bounded + PartialOrd + Copy,
+    I: Iterator<Item = (T, T)>,
+{
+    let mut edges: Vec<Option<T>> = ranges
+        .flat_map(|(start, end)| vec![Some(start), successor(end)])
+        .collect();
+    edges.sort_by(|a, b| match (a, b) {
+        (Some(a), Some(b)) => a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+    edges.dedup();
+    edges
+}
+
+/// Pairs each edge produced by [sorted_edges] with the edge following it,
+/// yielding the start of each slab along with its exclusive end.
+fn slabs<T: Copy>(edges: &[Option<T>]) -> impl Iterator<Item = (T, Option<T>)> + '_ {
+    edges
+        .windows(2)
+        .filter_map(|pair| pair[0].map(|start| (start, pair[1])))
+}
+
+/// Returns the number of values from `start` up to the exclusive `end`, where
+/// an `end` of `None` lies just beyond the largest value of `T`.
+fn slab_width<T>(start: T, end: Option<T>) -> T
+where
+    T: Num + Bounded + PartialOrd + Copy,
+{
+    match end {
+        Some(end) => end - start,
+        None => T::max_value() - start + T::one(),
+    }
+}
+
+/// Returns `v + 1`, or `None` if `v` is the largest value of `T`.
+fn successor<T>(v: T) -> Option<T>
+where
+    T: Num + Bounded + PartialOrd + Copy,
+{
+    if v < T::max_value() {
+        Some(v + T::one())
+    } else {
+        None
+    }
+}
+
+/// Returns the number of values covered by the union of the given inclusive
+/// ranges.
+fn covered_length<T, I>(ranges: I) -> T
+where
+    T: Num + Bounded + PartialOrd + Copy,
+    I: Iterator<Item = (T, T)>,
+{
+    let mut ranges: Vec<(T, T)> = ranges.collect();
+    ranges.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut total = T::zero();
+    let mut current: Option<(T, T)> = None;
+    for (start, end) in ranges {
+        current = match current {
+            Some((cs, ce)) if start <= ce || successor(ce) == Some(start) => {
+                Some((cs, max(ce, end)))
+            }
+            Some((cs, ce)) => {
+                total = total + (ce - cs + T::one());
+                Some((start, end))
+            }
+            None => Some((start, end)),
+        };
+    }
+
+    match current {
+        Some((cs, ce)) => total + (ce - cs + T::one()),
+        None => total,
+    }
+}
+
+/// Yields every value from `start` to `end`, inclusive.
+fn inclusive<T>(start: T, end: T) -> impl Iterator<Item = T>
+where
+    T: Num + PartialOrd + Copy,
+{
+    let first = if start <= end { Some(start) } else { None };
+    std::iter::successors(
+        first,
+        move |v| if *v < end { Some(*v + T::one()) } else { None },
+    )
+}
+
+pub(super) fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+pub(super) fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+fn saturating_sub<T>(a: T, b: T) -> T
+where
+    T: Num + Bounded + PartialOrd + Copy,
+{
+    if b < T::zero() {
+        // subtracting a negative value moves towards the maximum
+        if a <= T::max_value() + b {
+            a - b
+        } else {
+            T::max_value()
+        }
+    } else if a >= T::min_value() + b {
+        a - b
+    } else {
+        T::min_value()
+    }
+}
+
+fn saturating_add<T>(a: T, b: T) -> T
+where
+    T: Num + Bounded + PartialOrd + Copy,
+{
+    if b < T::zero() {
+        // adding a negative value moves towards the minimum
+        if a >= T::min_value() - b {
+            a + b
+        } else {
+            T::min_value()
+        }
+    } else if a <= T::max_value() - b {
+        a + b
+    } else {
+        T::max_value()
+    }
+}
+
+/// Returns the distance from `from` up to `to` as a [usize], or `None` if `to`
+/// is smaller or the distance does not fit. The distance is computed in a
+/// wider type, so it may exceed the largest value of `T`.
+fn distance<T: NumCast>(from: T, to: T) -> Option<usize> {
+    match (from.to_u128(), to.to_u128()) {
+        (Some(from), Some(to)) => usize::try_from(to.checked_sub(from)?).ok(),
+        _ => usize::try_from(to.to_i128()?.checked_sub(from.to_i128()?)?).ok(),
+    }
+}
+
+/// Returns the value `n` steps above `from`, or `None` if it is not
+/// representable by `T`. The inverse of [distance].
+fn advance<T: NumCast>(from: T, n: usize) -> Option<T> {
+    match from.to_u128() {
+        Some(from) => T::from(from.checked_add(n as u128)?),
+        None => T::from(from.to_i128()?.checked_add(n as i128)?),
+    }
 }
 
 // Special case for usize
@@ -78,6 +594,120 @@ impl Bound2D<usize> {
     }
 }
 
+impl<T> Bound2D<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Default + Hash + NumCast,
+{
+    /// Maps an `(x, y)` point within this bound to the [Location] of the
+    /// corresponding cell in a [Grid] covering this bound, where `min_y` is
+    /// row zero and `min_x` is column zero. Returns `None` if the point is
+    /// outside of this bound.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::{Bound2D, Location};
+    ///
+    /// let b = Bound2D::new(-5, 5, -2, 2);
+    /// assert_eq!(b.to_location(-5, -2), Some(Location::new(0, 0)));
+    /// assert_eq!(b.to_location(3, 1), Some(Location::new(3, 8)));
+    /// assert_eq!(b.to_location(6, 1), None);
+    /// ```
+    pub fn to_location(&self, x: T, y: T) -> Option<Location> {
+        if !self.contains(x, y) {
+            return None;
+        }
+
+        Some(Location::new(
+            distance(self.min_y, y)?,
+            distance(self.min_x, x)?,
+        ))
+    }
+
+    /// The inverse of [`to_location`](Bound2D::to_location). Returns `None` if
+    /// the location does not correspond to a point within this bound.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::{Bound2D, Location};
+    ///
+    /// let b = Bound2D::new(-5, 5, -2, 2);
+    /// assert_eq!(b.from_location(&Location::new(3, 8)), Some((3, 1)));
+    /// assert_eq!(b.from_location(&Location::new(5, 0)), None);
+    /// ```
+    pub fn from_location(&self, loc: &Location) -> Option<(T, T)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let (x, y) = (advance(self.min_x, loc.col)?, advance(self.min_y, loc.row)?);
+        if x > self.max_x || y > self.max_y {
+            return None;
+        }
+
+        Some((x, y))
+    }
+
+    /// Constructs a [Grid] covering this bound, where every cell is `default`
+    /// except those given by `points`. Points outside of this bound are
+    /// ignored.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::Bound2D;
+    ///
+    /// let points = vec![((-1, 10), '#'), ((1, 11), '#')];
+    /// let bound: Bound2D<i64> = points.iter().map(|(p, _)| *p).collect();
+    /// let grid = bound.to_grid(points, '.');
+    ///
+    /// assert_eq!(grid.to_string(), "#..\n..#");
+    /// ```
+    pub fn to_grid<V, I>(&self, points: I, default: V) -> Grid<V>
+    where
+        V: Debug + Clone,
+        I: IntoIterator<Item = ((T, T), V)>,
+    {
+        let rows = if self.is_empty() {
+            0
+        } else {
+            self.height().to_usize().unwrap_or_default()
+        };
+        let cols = if self.is_empty() {
+            0
+        } else {
+            self.width().to_usize().unwrap_or_default()
+        };
+
+        let mut grid = Grid::new(vec![vec![default; cols]; rows]);
+        for ((x, y), value) in points {
+            if let Some(loc) = self.to_location(x, y) {
+                grid.set(&loc, value);
+            }
+        }
+
+        grid
+    }
+
+    /// Yields the `(x, y)` point for every cell of `grid` along with its
+    /// value, treating the grid as covering this bound. The inverse of
+    /// [`to_grid`](Bound2D::to_grid).
+    pub fn grid_points<'a, V>(
+        &'a self,
+        grid: &'a Grid<V>,
+    ) -> impl Iterator<Item = ((T, T), &'a V)> + 'a
+    where
+        V: Debug + Clone,
+    {
+        grid.locations
+            .iter()
+            .enumerate()
+            .flat_map(move |(row, values)| {
+                values.iter().enumerate().filter_map(move |(col, v)| {
+                    self.from_location(&Location::new(row, col)).map(|p| (p, v))
+                })
+            })
+    }
+}
+
 impl<T> fmt::Display for Bound2D<T>
 where
     T: Num + Bounded + PartialOrd + Copy + Default + Hash + Display,
@@ -91,6 +721,234 @@ where
     }
 }
 
+/// A three-dimensional counterpart to [Bound2D], describing an axis-aligned
+/// cuboid with inclusive bounds.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Bound3D<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Default + Hash,
+{
+    pub min_x: T,
+    pub max_x: T,
+    pub min_y: T,
+    pub max_y: T,
+    pub min_z: T,
+    pub max_z: T,
+}
+
+impl<T> Bound3D<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Default + Hash,
+{
+    pub fn new(min_x: T, max_x: T, min_y: T, max_y: T, min_z: T, max_z: T) -> Self {
+        Self {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+            min_z,
+            max_z,
+        }
+    }
+
+    /// Like [`Bound2D::minmax`], an initial state for generating a bound via
+    /// iteration.
+    pub fn minmax() -> Self {
+        Self::new(
+            T::max_value(),
+            T::min_value(),
+            T::max_value(),
+            T::min_value(),
+            T::max_value(),
+            T::min_value(),
+        )
+    }
+
+    pub fn contains(&self, x: T, y: T, z: T) -> bool {
+        self.min_x <= x
+            && self.max_x >= x
+            && self.min_y <= y
+            && self.max_y >= y
+            && self.min_z <= z
+            && self.max_z >= z
+    }
+
+    pub fn width(&self) -> T {
+        self.max_x - self.min_x + T::one()
+    }
+
+    pub fn height(&self) -> T {
+        self.max_y - self.min_y + T::one()
+    }
+
+    pub fn depth(&self) -> T {
+        self.max_z - self.min_z + T::one()
+    }
+
+    pub fn volume(&self) -> T {
+        self.width() * self.height() * self.depth()
+    }
+
+    /// Whether or not this bound contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.min_x > self.max_x || self.min_y > self.max_y || self.min_z > self.max_z
+    }
+
+    /// Grow this bound, if necessary, to include the given point.
+    pub fn extend_with(&mut self, x: T, y: T, z: T) {
+        self.min_x = min(self.min_x, x);
+        self.max_x = max(self.max_x, x);
+        self.min_y = min(self.min_y, y);
+        self.max_y = max(self.max_y, y);
+        self.min_z = min(self.min_z, z);
+        self.max_z = max(self.max_z, z);
+    }
+
+    /// Returns the bound covered by both this bound and `other`, if any.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let res = Self::new(
+            max(self.min_x, other.min_x),
+            min(self.max_x, other.max_x),
+            max(self.min_y, other.min_y),
+            min(self.max_y, other.max_y),
+            max(self.min_z, other.min_z),
+            min(self.max_z, other.max_z),
+        );
+
+        if res.is_empty() {
+            None
+        } else {
+            Some(res)
+        }
+    }
+
+    /// Whether or not this bound shares at least one point with `other`.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the disjoint pieces of this bound not covered by `other`. There
+    /// will be at most six pieces: slabs before and after `other` along the z
+    /// axis, then along the y axis, then along the x axis.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::Bound3D;
+    ///
+    /// let cube = Bound3D::new(0, 2, 0, 2, 0, 2);
+    /// let center = Bound3D::new(1, 1, 1, 1, 1, 1);
+    /// let pieces = cube.subtract(&center);
+    ///
+    /// assert_eq!(pieces.len(), 6);
+    /// assert_eq!(pieces.iter().map(|p| p.volume()).sum::<i32>(), 26);
+    /// ```
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let i = match self.intersection(other) {
+            Some(i) => i,
+            None => return vec![*self],
+        };
+
+        let one = T::one();
+        // each piece is only produced when `i` stops short of that side of
+        // `self`, so stepping past `i` never leaves the range of `T`
+        let mut pieces = Vec::with_capacity(6);
+        if i.min_z > self.min_z {
+            pieces.push(Self::new(
+                self.min_x,
+                self.max_x,
+                self.min_y,
+                self.max_y,
+                self.min_z,
+                i.min_z - one,
+            ));
+        }
+        if i.max_z < self.max_z {
+            pieces.push(Self::new(
+                self.min_x,
+                self.max_x,
+                self.min_y,
+                self.max_y,
+                i.max_z + one,
+                self.max_z,
+            ));
+        }
+        if i.min_y > self.min_y {
+            pieces.push(Self::new(
+                self.min_x,
+                self.max_x,
+                self.min_y,
+                i.min_y - one,
+                i.min_z,
+                i.max_z,
+            ));
+        }
+        if i.max_y < self.max_y {
+            pieces.push(Self::new(
+                self.min_x,
+                self.max_x,
+                i.max_y + one,
+                self.max_y,
+                i.min_z,
+                i.max_z,
+            ));
+        }
+        if i.min_x > self.min_x {
+            pieces.push(Self::new(
+                self.min_x,
+                i.min_x - one,
+                i.min_y,
+                i.max_y,
+                i.min_z,
+                i.max_z,
+            ));
+        }
+        if i.max_x < self.max_x {
+            pieces.push(Self::new(
+                i.max_x + one,
+                self.max_x,
+                i.min_y,
+                i.max_y,
+                i.min_z,
+                i.max_z,
+            ));
+        }
+
+        pieces
+    }
+
+    /// Returns the total volume covered by the given bounds, counting regions
+    /// covered by more than one bound only once.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::Bound3D;
+    ///
+    /// let cuboids = vec![
+    ///     Bound3D::new(10, 12, 10, 12, 10, 12),
+    ///     Bound3D::new(11, 13, 11, 13, 11, 13),
+    /// ];
+    /// assert_eq!(Bound3D::union_volume(&cuboids), 27 + 27 - 8);
+    /// ```
+    pub fn union_volume(bounds: &[Self]) -> T {
+        let bounds: Vec<_> = bounds.iter().filter(|b| !b.is_empty()).collect();
+        let xs = sorted_edges(bounds.iter().map(|b| (b.min_x, b.max_x)));
+
+        slabs(&xs).fold(T::zero(), |acc, (x, next)| {
+            let faces: Vec<Bound2D<T>> = bounds
+                .iter()
+                .filter(|b| b.min_x <= x && b.max_x >= x)
+                .map(|b| Bound2D::new(b.min_y, b.max_y, b.min_z, b.max_z))
+                .collect();
+            let area = Bound2D::union_area(&faces);
+            if area == T::zero() {
+                acc
+            } else {
+                acc + area * slab_width(x, next)
+            }
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,4 +985,368 @@ mod tests {
         let expected = "Bounds: min (x: -1, y: -10), max (x: 1, y: 10)".to_string();
         assert_eq!(b.to_string(), expected);
     }
+
+    #[test]
+    fn from_iter() {
+        let b: Bound2D<i32> = vec![(0, 0), (5, -3), (-2, 8)].into_iter().collect();
+        assert_eq!(b, Bound2D::new(-2, 5, -3, 8));
+
+        let b: Bound2D<usize> = vec![(3, 4)].into_iter().collect();
+        assert_eq!(b, Bound2D::new(3, 3, 4, 4));
+        assert_eq!(b.area(), 1);
+
+        let b: Bound2D<u8> = std::iter::empty().collect();
+        assert!(b.is_empty());
+        assert_eq!(b, Bound2D::minmax());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = Bound2D::new(0_i64, 10, 0, 10);
+        let b = Bound2D::new(5_i64, 15, -5, 5);
+        let c = Bound2D::new(11_i64, 12, 11, 12);
+
+        assert_eq!(a.union(&b), Bound2D::new(0, 15, -5, 10));
+        assert_eq!(a.union(&Bound2D::minmax()), a);
+        assert_eq!(a.intersection(&b), Some(Bound2D::new(5, 10, 0, 5)));
+        assert_eq!(a.intersection(&c), None);
+        assert!(a.overlaps(&b));
+        assert!(b.overlaps(&a));
+        assert!(!a.overlaps(&c));
+
+        // touching edges overlap, since bounds are inclusive
+        assert!(a.overlaps(&Bound2D::new(10, 20, 10, 20)));
+
+        assert!(a.contains_bound(&Bound2D::new(1, 2, 3, 4)));
+        assert!(a.contains_bound(&a));
+        assert!(!a.contains_bound(&b));
+    }
+
+    #[test]
+    fn margins() {
+        let b = Bound2D::new(-1_i32, 1, -10, 10);
+        assert_eq!(b.expand(3), Bound2D::new(-4, 4, -13, 13));
+        assert_eq!(b.expand(3).shrink(3), Some(b));
+        assert_eq!(b.shrink(1), Some(Bound2D::new(0, 0, -9, 9)));
+        assert_eq!(b.shrink(2), None);
+
+        let b = Bound2D::new(0_usize, 2, 1, 3);
+        assert_eq!(b.expand(2), Bound2D::new(0, 4, 0, 5));
+
+        let b = Bound2D::new(250_u8, 254, 0, 0);
+        assert_eq!(b.expand(3), Bound2D::new(247, 255, 0, 3));
+        assert_eq!(b.shrink(1), None);
+        assert_eq!(Bound2D::<u8>::minmax().shrink(0), None);
+    }
+
+    #[test]
+    fn negative_margins() {
+        let b = Bound2D::new(-1_i32, 1, -10, 10);
+        assert_eq!(b.expand(-1), Bound2D::new(0, 0, -9, 9));
+        assert!(b.expand(-2).is_empty());
+        assert_eq!(b.shrink(-3), Some(b.expand(3)));
+
+        let wide = Bound2D::new(i32::MIN, i32::MAX, i32::MIN, i32::MAX);
+        assert_eq!(wide.expand(5), wide);
+        assert_eq!(wide.shrink(-5), Some(wide));
+        assert_eq!(
+            wide.shrink(5),
+            Some(Bound2D::new(
+                i32::MIN + 5,
+                i32::MAX - 5,
+                i32::MIN + 5,
+                i32::MAX - 5
+            ))
+        );
+        assert_eq!(wide.shrink(i32::MAX), Some(Bound2D::new(-1, 0, -1, 0)));
+        assert_eq!(wide.expand(i32::MIN), Bound2D::new(0, -1, 0, -1));
+
+        let edge = Bound2D::new(i32::MIN, i32::MIN + 1, i32::MAX - 1, i32::MAX);
+        assert_eq!(
+            edge.expand(-1),
+            Bound2D::new(i32::MIN + 1, i32::MIN, i32::MAX, i32::MAX - 1)
+        );
+        assert_eq!(edge.shrink(1), None);
+    }
+
+    #[test]
+    fn corners() {
+        let b = Bound2D::new(-1, 1, -10, 10);
+        assert_eq!(b.min_corner(), (-1, -10));
+        assert_eq!(b.max_corner(), (1, 10));
+        assert_eq!(b.corners(), [(-1, -10), (1, -10), (1, 10), (-1, 10)]);
+        assert!(b.corners().iter().all(|(x, y)| b.contains(*x, *y)));
+    }
+
+    #[test]
+    fn points() {
+        let b = Bound2D::new(-1, 1, -10, 10);
+        let points: Vec<_> = b.points().collect();
+        assert_eq!(points.len() as i32, b.area());
+        assert!(points.iter().all(|(x, y)| b.contains(*x, *y)));
+        assert_eq!(points.first(), Some(&(-1, -10)));
+        assert_eq!(points[1], (0, -10));
+        assert_eq!(points.last(), Some(&(1, 10)));
+
+        let b: Bound2D<u8> = Bound2D::new(254, 255, 0, 0);
+        assert_eq!(b.points().collect::<Vec<_>>(), vec![(254, 0), (255, 0)]);
+
+        let b: Bound2D<i32> = Bound2D::minmax();
+        assert_eq!(b.points().count(), 0);
+    }
+
+    #[test]
+    fn rendering() {
+        use crate::generic::Grid;
+
+        let points: HashSet<(i64, i64)> = vec![(10, -3), (11, -2), (12, -3), (12, -1)]
+            .into_iter()
+            .collect();
+        let res = Bound2D::render_set(&points);
+        let expected = Grid::new(vec![
+            vec!['#', '.', '#'],
+            vec!['.', '#', '.'],
+            vec!['.', '.', '#'],
+        ]);
+        assert_eq!(res, expected.to_string());
+
+        let map: HashMap<(usize, usize), char> =
+            vec![((0, 0), 'a'), ((2, 1), 'b')].into_iter().collect();
+        assert_eq!(Bound2D::render_map(&map, '.'), "a..\n..b");
+
+        assert_eq!(Bound2D::render_set(&HashSet::<(i32, i32)>::new()), "");
+    }
+
+    #[test]
+    fn locations() {
+        let b = Bound2D::new(-3_i32, 4, 7, 9);
+        for (x, y) in b.points() {
+            let loc = b.to_location(x, y).unwrap();
+            assert_eq!(b.from_location(&loc), Some((x, y)));
+        }
+
+        assert_eq!(b.to_location(-4, 7), None);
+        assert_eq!(b.to_location(0, 10), None);
+        assert_eq!(b.from_location(&Location::new(3, 0)), None);
+        assert_eq!(b.from_location(&Location::new(0, 8)), None);
+
+        // agrees with the special case for usize
+        let b = Bound2D::new(2_usize, 8, 3, 6);
+        let loc = Location::new(5, 4);
+        assert_eq!(b.to_location(loc.col, loc.row), Some(b.translate(&loc)));
+
+        let b = Bound2D::new(250_u8, 255, 0, 1);
+        assert_eq!(b.from_location(&Location::new(1, 5)), Some((255, 1)));
+        assert_eq!(b.from_location(&Location::new(0, 6)), None);
+
+        // wider than T itself
+        let b = Bound2D::new(i32::MIN, i32::MAX, -1, 1);
+        let far = Location::new(2, u32::MAX as usize);
+        assert_eq!(b.to_location(i32::MAX, 1), Some(far));
+        assert_eq!(b.to_location(i32::MIN, -1), Some(Location::new(0, 0)));
+        assert_eq!(b.from_location(&far), Some((i32::MAX, 1)));
+        assert_eq!(b.from_location(&Location::new(0, far.col + 1)), None);
+        assert_eq!(b.from_location(&Location::new(3, 0)), None);
+
+        let b = Bound2D::new(0_u128, u128::MAX, u128::MAX - 1, u128::MAX);
+        assert_eq!(b.to_location(5, u128::MAX), Some(Location::new(1, 5)));
+        assert_eq!(b.from_location(&Location::new(1, 5)), Some((5, u128::MAX)));
+        assert_eq!(b.from_location(&Location::new(2, 5)), None);
+    }
+
+    #[test]
+    fn grids() {
+        let points: HashSet<(i64, i64)> = vec![(-10, 3), (-9, 4), (-8, 3), (-8, 5)]
+            .into_iter()
+            .collect();
+        let b: Bound2D<i64> = points.iter().copied().collect();
+
+        let grid = b.to_grid(points.iter().map(|p| (*p, '#')), '.');
+        assert_eq!(grid.rows, 3);
+        assert_eq!(grid.cols, 3);
+        assert_eq!(grid.to_string(), Bound2D::render_set(&points));
+
+        let round_trip: HashSet<(i64, i64)> = b
+            .grid_points(&grid)
+            .filter(|(_, v)| **v == '#')
+            .map(|(p, _)| p)
+            .collect();
+        assert_eq!(round_trip, points);
+
+        let empty: Grid<char> = Bound2D::<i64>::minmax().to_grid(vec![], '.');
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn subtraction() {
+        let a = Bound2D::new(0_i64, 9, 0, 9);
+        for other in [
+            Bound2D::new(3, 5, 3, 5),
+            Bound2D::new(-5, 5, -5, 5),
+            Bound2D::new(0, 9, 4, 4),
+            Bound2D::new(20, 30, 0, 9),
+            Bound2D::new(-1, 10, -1, 10),
+            Bound2D::new(9, 9, 0, 0),
+        ] {
+            let pieces = a.subtract(&other);
+            assert!(pieces.len() <= 4);
+
+            let covered = a.intersection(&other).map(|i| i.area()).unwrap_or(0);
+            assert_eq!(
+                pieces.iter().map(|p| p.area()).sum::<i64>(),
+                a.area() - covered
+            );
+
+            for p in &pieces {
+                assert!(a.contains_bound(p));
+                assert!(!p.overlaps(&other));
+            }
+
+            for (i, p) in pieces.iter().enumerate() {
+                for q in pieces.iter().skip(i + 1) {
+                    assert!(!p.overlaps(q));
+                }
+            }
+        }
+
+        let a = Bound2D::new(0_usize, 3, 0, 3);
+        assert_eq!(
+            a.subtract(&Bound2D::new(0, 3, 0, 1)),
+            vec![Bound2D::new(0, 3, 2, 3)]
+        );
+    }
+
+    #[test]
+    fn union_area() {
+        assert_eq!(Bound2D::<i32>::union_area(&[]), 0);
+
+        let bounds = vec![
+            Bound2D::new(0_i32, 4, 0, 4),
+            Bound2D::new(2, 6, 2, 6),
+            Bound2D::new(10, 10, 10, 10),
+            Bound2D::new(1, 1, 1, 1),
+            Bound2D::minmax(),
+        ];
+
+        let expected = Bound2D::new(-1, 11, -1, 11)
+            .points()
+            .filter(|(x, y)| bounds.iter().any(|b| b.contains(*x, *y)))
+            .count() as i32;
+        assert_eq!(Bound2D::union_area(&bounds), expected);
+        assert_eq!(expected, 25 + 25 - 9 + 1);
+    }
+
+    #[test]
+    fn bound3d() {
+        let a = Bound3D::new(0_i64, 3, 0, 3, 0, 3);
+        assert_eq!(a.volume(), 64);
+        assert!(a.contains(3, 0, 2));
+        assert!(!a.contains(3, 0, 4));
+
+        let mut b = Bound3D::minmax();
+        assert!(b.is_empty());
+        b.extend_with(2, 2, 2);
+        b.extend_with(5, 4, 3);
+        assert_eq!(b, Bound3D::new(2, 5, 2, 4, 2, 3));
+        assert_eq!(a.intersection(&b), Some(Bound3D::new(2, 3, 2, 3, 2, 3)));
+        assert!(!a.overlaps(&Bound3D::new(4, 5, 0, 0, 0, 0)));
+
+        for other in [
+            b,
+            Bound3D::new(1, 2, 1, 2, 1, 2),
+            Bound3D::new(-1, 9, 1, 1, -1, 9),
+        ] {
+            let pieces = a.subtract(&other);
+            assert!(pieces.len() <= 6);
+            let covered = a.intersection(&other).map(|i| i.volume()).unwrap_or(0);
+            assert_eq!(
+                pieces.iter().map(|p| p.volume()).sum::<i64>(),
+                a.volume() - covered
+            );
+            for p in &pieces {
+                assert!(!p.overlaps(&other));
+            }
+        }
+    }
+
+    #[test]
+    fn union_volume() {
+        let cuboids = vec![
+            Bound3D::new(0_i64, 2, 0, 2, 0, 2),
+            Bound3D::new(1, 3, 1, 3, 1, 3),
+            Bound3D::new(-2, -1, 0, 0, 0, 0),
+        ];
+
+        let mut expected = 0;
+        for x in -3..5 {
+            for y in -3..5 {
+                for z in -3..5 {
+                    if cuboids.iter().any(|c| c.contains(x, y, z)) {
+                        expected += 1;
+                    }
+                }
+            }
+        }
+
+        assert_eq!(Bound3D::union_volume(&cuboids), expected);
+        assert_eq!(expected, 27 + 27 - 8 + 2);
+    }
+
+    #[test]
+    fn limits() {
+        assert_eq!(Bound2D::union_area(&[Bound2D::new(1_u8, 255, 0, 0)]), 255);
+        assert_eq!(
+            Bound2D::union_area(&[
+                Bound2D::new(250_u8, 255, 250, 255),
+                Bound2D::new(252, 255, 252, 255),
+            ]),
+            36
+        );
+        assert_eq!(
+            Bound2D::union_area(&[
+                Bound2D::new(1_u8, 254, 255, 255),
+                Bound2D::new(255, 255, 0, 0),
+            ]),
+            255
+        );
+        assert_eq!(
+            Bound2D::union_area(&[Bound2D::new(0_u8, 0, 1, 100), Bound2D::new(0, 0, 101, 255),]),
+            255
+        );
+        assert_eq!(
+            Bound2D::union_area(&[
+                Bound2D::new(i32::MIN, i32::MIN + 1, 0, 0),
+                Bound2D::new(i32::MAX - 1, i32::MAX, 0, 0),
+            ]),
+            4
+        );
+
+        assert_eq!(
+            Bound2D::new(0_u8, 255, 0, 255).subtract(&Bound2D::new(0, 255, 250, 255)),
+            vec![Bound2D::new(0, 255, 0, 249)]
+        );
+        assert_eq!(
+            Bound2D::new(200_u8, 255, 200, 255).subtract(&Bound2D::new(250, 255, 250, 255)),
+            vec![
+                Bound2D::new(200, 255, 200, 249),
+                Bound2D::new(200, 249, 250, 255)
+            ]
+        );
+        assert_eq!(
+            Bound2D::new(0_u8, 5, 0, 5).subtract(&Bound2D::new(0, 0, 0, 0)),
+            vec![Bound2D::new(0, 5, 1, 5), Bound2D::new(1, 5, 0, 0)]
+        );
+
+        assert_eq!(
+            Bound3D::union_volume(&[
+                Bound3D::new(254_u8, 255, 254, 255, 254, 255),
+                Bound3D::new(255, 255, 0, 3, 255, 255),
+            ]),
+            12
+        );
+        assert_eq!(
+            Bound3D::new(250_u8, 255, 0, 0, 0, 0).subtract(&Bound3D::new(255, 255, 0, 0, 0, 0)),
+            vec![Bound3D::new(250, 254, 0, 0, 0, 0)]
+        );
+    }
 }
@@ -1,6 +1,6 @@
 //! This module contains several Enums representing different sets compass
 //! directions.
-use std::{convert::TryFrom, fmt, str::FromStr};
+use std::{convert::TryFrom, fmt, ops::Neg, str::FromStr};
 
 use crate::error::AocError;
 
@@ -47,6 +47,126 @@ pub enum Direction {
     NorthWest,
 }
 
+impl Direction {
+    /// All variants, in clockwise order starting from North.
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    fn index(&self) -> usize {
+        match self {
+            Self::North => 0,
+            Self::NorthEast => 1,
+            Self::East => 2,
+            Self::SouthEast => 3,
+            Self::South => 4,
+            Self::SouthWest => 5,
+            Self::West => 6,
+            Self::NorthWest => 7,
+        }
+    }
+
+    fn rotate(&self, steps: usize) -> Self {
+        Self::ALL[(self.index() + steps) % Self::ALL.len()]
+    }
+
+    /// Returns the direction facing the opposite way. Also available as `-dir`.
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub fn right_45(&self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Turns 45 degrees counter-clockwise.
+    pub fn left_45(&self) -> Self {
+        self.rotate(7)
+    }
+
+    /// Turns 90 degrees clockwise.
+    pub fn right_90(&self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Turns 90 degrees counter-clockwise.
+    pub fn left_90(&self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Returns the direction resulting from applying the given [Turn], where
+    /// left and right are 90 degree turns.
+    ///
+    /// Example:
+    /// ```
+    /// use aoc_helpers::generic::directions::{Direction, Turn};
+    ///
+    /// assert_eq!(Direction::NorthEast.turn(Turn::Right), Direction::SouthEast);
+    /// assert_eq!(Direction::NorthEast.turn(Turn::Back), Direction::SouthWest);
+    /// ```
+    pub fn turn(&self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.left_90(),
+            Turn::Right => self.right_90(),
+            Turn::Straight => *self,
+            Turn::Back => self.opposite(),
+        }
+    }
+
+    /// Returns the `(row, col)` offset of a single step in this direction,
+    /// where North decreases the row and East increases the column.
+    ///
+    /// Example:
+    /// ```
+    /// use aoc_helpers::generic::directions::Direction;
+    ///
+    /// assert_eq!(Direction::North.delta(), (-1, 0));
+    /// assert_eq!(Direction::SouthEast.delta(), (1, 1));
+    /// ```
+    pub fn delta(&self) -> (i64, i64) {
+        match self {
+            Self::North => (-1, 0),
+            Self::NorthEast => (-1, 1),
+            Self::East => (0, 1),
+            Self::SouthEast => (1, 1),
+            Self::South => (1, 0),
+            Self::SouthWest => (1, -1),
+            Self::West => (0, -1),
+            Self::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Returns the clockwise angle, in degrees, needed to turn from this
+    /// direction to `other`. The result is always in `0..360`.
+    ///
+    /// Example:
+    /// ```
+    /// use aoc_helpers::generic::directions::Direction;
+    ///
+    /// assert_eq!(Direction::North.angle_to(&Direction::East), 90);
+    /// assert_eq!(Direction::North.angle_to(&Direction::NorthWest), 315);
+    /// ```
+    pub fn angle_to(&self, other: &Self) -> i64 {
+        ((other.index() + Self::ALL.len() - self.index()) % Self::ALL.len()) as i64 * 45
+    }
+}
+
+impl Neg for Direction {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.opposite()
+    }
+}
+
 impl FromStr for Direction {
     type Err = AocError;
 
@@ -160,6 +280,20 @@ impl From<&VertHexDir> for Direction {
 /// assert_eq!(Cardinal::try_from('n').unwrap(), Cardinal::North);
 /// assert_eq!(Cardinal::try_from('N').unwrap(), Cardinal::North);
 /// ```
+///
+/// Arrows (`^`, `>`, `v`, `<`) and `U`/`D`/`L`/`R` letters are accepted as
+/// well, with up being North.
+///
+/// Example:
+/// ```
+/// use std::convert::TryFrom;
+/// use aoc_helpers::generic::directions::Cardinal;
+///
+/// assert_eq!(Cardinal::try_from('^').unwrap(), Cardinal::North);
+/// assert_eq!(Cardinal::try_from('<').unwrap(), Cardinal::West);
+/// assert_eq!(Cardinal::try_from('D').unwrap(), Cardinal::South);
+/// assert_eq!(Cardinal::try_from('r').unwrap(), Cardinal::East);
+/// ```
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum Cardinal {
     North,
@@ -169,6 +303,57 @@ pub enum Cardinal {
 }
 
 impl Cardinal {
+    /// All variants, in clockwise order starting from North.
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    fn index(&self) -> usize {
+        match self {
+            Self::North => 0,
+            Self::East => 1,
+            Self::South => 2,
+            Self::West => 3,
+        }
+    }
+
+    /// Returns the direction facing the opposite way. Also available as `-dir`.
+    pub fn opposite(&self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::South => Self::North,
+            Self::East => Self::West,
+            Self::West => Self::East,
+        }
+    }
+
+    /// Returns the `(row, col)` offset of a single step in this direction,
+    /// where North decreases the row and East increases the column.
+    ///
+    /// Example:
+    /// ```
+    /// use aoc_helpers::generic::directions::Cardinal;
+    ///
+    /// assert_eq!(Cardinal::North.delta(), (-1, 0));
+    /// assert_eq!(Cardinal::East.delta(), (0, 1));
+    /// ```
+    pub fn delta(&self) -> (i64, i64) {
+        Direction::from(self).delta()
+    }
+
+    /// Returns the clockwise angle, in degrees, needed to turn from this
+    /// direction to `other`. The result is always in `0..360`.
+    ///
+    /// Example:
+    /// ```
+    /// use aoc_helpers::generic::directions::Cardinal;
+    ///
+    /// assert_eq!(Cardinal::North.angle_to(&Cardinal::West), 270);
+    /// assert_eq!(Cardinal::West.angle_to(&Cardinal::North), 90);
+    /// ```
+    pub fn angle_to(&self, other: &Self) -> i64 {
+        ((other.index() + Self::ALL.len() - self.index()) % Self::ALL.len()) as i64 * 90
+    }
+
+    /// Turns 90 degrees clockwise.
     pub fn right(&self) -> Self {
         match self {
             Self::North => Self::East,
@@ -178,6 +363,7 @@ impl Cardinal {
         }
     }
 
+    /// Turns 90 degrees counter-clockwise.
     pub fn left(&self) -> Self {
         match self {
             Self::North => Self::West,
@@ -186,6 +372,32 @@ impl Cardinal {
             Self::West => Self::South,
         }
     }
+
+    /// Returns the direction resulting from applying the given [Turn].
+    ///
+    /// Example:
+    /// ```
+    /// use aoc_helpers::generic::directions::{Cardinal, Turn};
+    ///
+    /// assert_eq!(Cardinal::North.turn(Turn::Right), Cardinal::East);
+    /// assert_eq!(Cardinal::North.turn(Turn::Back), Cardinal::South);
+    /// ```
+    pub fn turn(&self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.left(),
+            Turn::Right => self.right(),
+            Turn::Straight => *self,
+            Turn::Back => self.opposite(),
+        }
+    }
+}
+
+impl Neg for Cardinal {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.opposite()
+    }
 }
 
 impl fmt::Display for Cardinal {
@@ -213,15 +425,158 @@ impl TryFrom<char> for Cardinal {
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value.to_ascii_lowercase() {
-            'n' => Ok(Self::North),
-            's' => Ok(Self::South),
-            'e' => Ok(Self::East),
-            'w' => Ok(Self::West),
+            'n' | 'u' | '^' => Ok(Self::North),
+            's' | 'd' | 'v' => Ok(Self::South),
+            'e' | 'r' | '>' => Ok(Self::East),
+            'w' | 'l' | '<' => Ok(Self::West),
             _ => Err(AocError::ParseDirectionError(value.to_string())),
         }
     }
 }
 
+/// Turn is an enum of turns relative to a current heading.
+///
+/// It can be parsed from strings like "left" or "l", and from single chars
+/// `L`, `R`, `S` (or `F`), and `B`, irrespective of case.
+///
+/// Example:
+/// ```
+/// use std::{convert::TryFrom, str::FromStr};
+/// use aoc_helpers::generic::directions::Turn;
+///
+/// assert_eq!(Turn::from_str("right").unwrap(), Turn::Right);
+/// assert_eq!(Turn::try_from('L').unwrap(), Turn::Left);
+/// assert_eq!(Turn::try_from('f').unwrap(), Turn::Straight);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+    Straight,
+    Back,
+}
+
+impl Turn {
+    /// Returns the turn that undoes this one.
+    pub fn inverse(&self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            Self::Straight => Self::Straight,
+            Self::Back => Self::Back,
+        }
+    }
+}
+
+impl FromStr for Turn {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "left" | "l" => Self::Left,
+            "right" | "r" => Self::Right,
+            "straight" | "s" | "forward" | "f" => Self::Straight,
+            "back" | "b" => Self::Back,
+            _ => return Err(AocError::ParseDirectionError(s.to_string())),
+        })
+    }
+}
+
+impl TryFrom<char> for Turn {
+    type Error = AocError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase() {
+            'l' => Ok(Self::Left),
+            'r' => Ok(Self::Right),
+            's' | 'f' => Ok(Self::Straight),
+            'b' => Ok(Self::Back),
+            _ => Err(AocError::ParseDirectionError(value.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Turn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let out = match self {
+            Self::Left => "Left",
+            Self::Right => "Right",
+            Self::Straight => "Straight",
+            Self::Back => "Back",
+        };
+        write!(f, "{}", out)
+    }
+}
+
+/// Parses a string of single-char movement instructions, each optionally
+/// followed by a number of steps, into a list of `(instruction, steps)`
+/// pairs. Instructions without a number default to a single step. Whitespace
+/// and commas between instructions are ignored.
+///
+/// The instruction type determines how chars are interpreted, so the same
+/// function handles both relative turns and absolute directions.
+///
+/// Example:
+/// ```
+/// use aoc_helpers::generic::directions::{parse_movements, Cardinal, Turn};
+///
+/// let turns: Vec<(Turn, usize)> = parse_movements("R2, L3").unwrap();
+/// assert_eq!(turns, vec![(Turn::Right, 2), (Turn::Left, 3)]);
+///
+/// let arrows: Vec<(Cardinal, usize)> = parse_movements("^>v<").unwrap();
+/// assert_eq!(arrows[1], (Cardinal::East, 1));
+///
+/// let lines: Vec<(Cardinal, usize)> = parse_movements("U 5\nL 12").unwrap();
+/// assert_eq!(lines, vec![(Cardinal::North, 5), (Cardinal::West, 12)]);
+/// ```
+pub fn parse_movements<T>(s: &str) -> Result<Vec<(T, usize)>, AocError>
+where
+    T: TryFrom<char, Error = AocError>,
+{
+    let mut out = Vec::new();
+    let mut chars = s.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch.is_whitespace() || ch == ',' {
+            continue;
+        }
+
+        if ch.is_ascii_digit() {
+            return Err(AocError::ParseDirectionError(format!(
+                "steps without a direction in '{}'",
+                s
+            )));
+        }
+
+        let instruction = T::try_from(ch)?;
+
+        while chars
+            .peek()
+            .map(|c| *c == ' ' || *c == '\t')
+            .unwrap_or(false)
+        {
+            chars.next();
+        }
+
+        let mut digits = String::new();
+        while let Some(c) = chars.peek().filter(|c| c.is_ascii_digit()) {
+            digits.push(*c);
+            chars.next();
+        }
+
+        let steps = if digits.is_empty() {
+            1
+        } else {
+            digits.parse().map_err(|_| {
+                AocError::ParseDirectionError(format!("invalid step count '{}' in '{}'", digits, s))
+            })?
+        };
+        out.push((instruction, steps));
+    }
+
+    Ok(out)
+}
+
 /// HorizHexDir is an enum of compass directions that represent valid faces of a
 /// hexagon with flat edges north and south.
 ///
@@ -262,6 +617,82 @@ pub enum HorizHexDir {
     SouthWest,
 }
 
+impl HorizHexDir {
+    /// All variants, in clockwise order starting from North.
+    pub const ALL: [Self; 6] = [
+        Self::North,
+        Self::NorthEast,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::NorthWest,
+    ];
+
+    fn index(&self) -> usize {
+        match self {
+            Self::North => 0,
+            Self::NorthEast => 1,
+            Self::SouthEast => 2,
+            Self::South => 3,
+            Self::SouthWest => 4,
+            Self::NorthWest => 5,
+        }
+    }
+
+    fn rotate(&self, steps: usize) -> Self {
+        Self::ALL[(self.index() + steps) % Self::ALL.len()]
+    }
+
+    /// Returns the direction facing the opposite way. Also available as `-dir`.
+    pub fn opposite(&self) -> Self {
+        self.rotate(3)
+    }
+
+    /// Turns 60 degrees clockwise.
+    pub fn right_60(&self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Turns 60 degrees counter-clockwise.
+    pub fn left_60(&self) -> Self {
+        self.rotate(5)
+    }
+
+    /// Returns the axial `(q, r)` offset of a single step in this direction.
+    ///
+    /// Example:
+    /// ```
+    /// use aoc_helpers::generic::directions::HorizHexDir;
+    ///
+    /// assert_eq!(HorizHexDir::North.delta(), (0, -1));
+    /// assert_eq!(HorizHexDir::SouthEast.delta(), (1, 0));
+    /// ```
+    pub fn delta(&self) -> (i64, i64) {
+        match self {
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::SouthEast => (1, 0),
+            Self::South => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::NorthWest => (-1, 0),
+        }
+    }
+
+    /// Returns the clockwise angle, in degrees, needed to turn from this
+    /// direction to `other`. The result is always in `0..360`.
+    pub fn angle_to(&self, other: &Self) -> i64 {
+        ((other.index() + Self::ALL.len() - self.index()) % Self::ALL.len()) as i64 * 60
+    }
+}
+
+impl Neg for HorizHexDir {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.opposite()
+    }
+}
+
 impl FromStr for HorizHexDir {
     type Err = AocError;
 
@@ -330,6 +761,82 @@ pub enum VertHexDir {
     SouthWest,
 }
 
+impl VertHexDir {
+    /// All variants, in clockwise order starting from NorthEast.
+    pub const ALL: [Self; 6] = [
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    fn index(&self) -> usize {
+        match self {
+            Self::NorthEast => 0,
+            Self::East => 1,
+            Self::SouthEast => 2,
+            Self::SouthWest => 3,
+            Self::West => 4,
+            Self::NorthWest => 5,
+        }
+    }
+
+    fn rotate(&self, steps: usize) -> Self {
+        Self::ALL[(self.index() + steps) % Self::ALL.len()]
+    }
+
+    /// Returns the direction facing the opposite way. Also available as `-dir`.
+    pub fn opposite(&self) -> Self {
+        self.rotate(3)
+    }
+
+    /// Turns 60 degrees clockwise.
+    pub fn right_60(&self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Turns 60 degrees counter-clockwise.
+    pub fn left_60(&self) -> Self {
+        self.rotate(5)
+    }
+
+    /// Returns the axial `(q, r)` offset of a single step in this direction.
+    ///
+    /// Example:
+    /// ```
+    /// use aoc_helpers::generic::directions::VertHexDir;
+    ///
+    /// assert_eq!(VertHexDir::East.delta(), (1, 0));
+    /// assert_eq!(VertHexDir::SouthEast.delta(), (0, 1));
+    /// ```
+    pub fn delta(&self) -> (i64, i64) {
+        match self {
+            Self::NorthEast => (1, -1),
+            Self::East => (1, 0),
+            Self::SouthEast => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (0, -1),
+        }
+    }
+
+    /// Returns the clockwise angle, in degrees, needed to turn from this
+    /// direction to `other`. The result is always in `0..360`.
+    pub fn angle_to(&self, other: &Self) -> i64 {
+        ((other.index() + Self::ALL.len() - self.index()) % Self::ALL.len()) as i64 * 60
+    }
+}
+
+impl Neg for VertHexDir {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.opposite()
+    }
+}
+
 impl FromStr for VertHexDir {
     type Err = AocError;
 
@@ -352,6 +859,64 @@ impl fmt::Display for VertHexDir {
     }
 }
 
+/// Parses a path of hex directions, like `ne,ne,s,sw` or `nenesesw`.
+///
+/// The input is split on commas and whitespace, and any piece that is not a
+/// single direction is read as a run of concatenated abbreviations, where `n`
+/// or `s` followed by `e` or `w` is taken as a single compound direction.
+///
+/// Example:
+/// ```
+/// use aoc_helpers::generic::directions::{parse_hex_path, HorizHexDir, VertHexDir};
+///
+/// let path: Vec<HorizHexDir> = parse_hex_path("ne,ne,s,sw").unwrap();
+/// assert_eq!(
+///     path,
+///     vec![HorizHexDir::NorthEast, HorizHexDir::NorthEast, HorizHexDir::South, HorizHexDir::SouthWest]
+/// );
+///
+/// let path: Vec<VertHexDir> = parse_hex_path("esenee").unwrap();
+/// assert_eq!(
+///     path,
+///     vec![VertHexDir::East, VertHexDir::SouthEast, VertHexDir::NorthEast, VertHexDir::East]
+/// );
+///
+/// // `n` is not a valid VertHexDir on its own
+/// assert!(parse_hex_path::<VertHexDir>("nnw").is_err());
+/// ```
+pub fn parse_hex_path<T>(s: &str) -> Result<Vec<T>, AocError>
+where
+    T: FromStr<Err = AocError>,
+{
+    let mut out = Vec::new();
+
+    for piece in s
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|p| !p.is_empty())
+    {
+        if let Ok(dir) = T::from_str(piece) {
+            out.push(dir);
+            continue;
+        }
+
+        let chars: Vec<char> = piece.chars().collect();
+        let mut idx = 0;
+        while idx < chars.len() {
+            let compound = matches!(chars[idx].to_ascii_lowercase(), 'n' | 's')
+                && chars
+                    .get(idx + 1)
+                    .map(|c| matches!(c.to_ascii_lowercase(), 'e' | 'w'))
+                    .unwrap_or(false);
+            let len = if compound { 2 } else { 1 };
+            let token: String = chars[idx..idx + len].iter().collect();
+            out.push(T::from_str(&token)?);
+            idx += len;
+        }
+    }
+
+    Ok(out)
+}
+
 #[cfg(test)]
 mod tests {
     mod cardinal {
@@ -375,5 +940,413 @@ mod tests {
                 assert_eq!(Cardinal::from_str(v).unwrap(), Cardinal::West);
             }
         }
+
+        #[test]
+        fn rotations() {
+            for d in Cardinal::ALL {
+                assert_eq!(d.opposite().opposite(), d);
+                assert_eq!(-d, d.opposite());
+                assert_eq!(d.right().left(), d);
+                assert_eq!(d.left().right(), d);
+                assert_eq!(d.right().right(), d.opposite());
+                assert_eq!(d.right().right().right().right(), d);
+                assert_eq!(d.left(), d.right().right().right());
+                assert_eq!(Direction::from(d.right()), Direction::from(d).right_90());
+                assert_eq!(Direction::from(d.left()), Direction::from(d).left_90());
+            }
+        }
+
+        #[test]
+        fn deltas() {
+            for d in Cardinal::ALL {
+                let (dr, dc) = d.delta();
+                assert_eq!(d.opposite().delta(), (-dr, -dc));
+                assert_eq!(dr.abs() + dc.abs(), 1);
+                assert_eq!(d.delta(), Direction::from(d).delta());
+            }
+        }
+
+        #[test]
+        fn angles() {
+            for d in Cardinal::ALL {
+                assert_eq!(d.angle_to(&d), 0);
+                assert_eq!(d.angle_to(&d.right()), 90);
+                assert_eq!(d.angle_to(&d.opposite()), 180);
+                assert_eq!(d.angle_to(&d.left()), 270);
+                for o in Cardinal::ALL {
+                    assert_eq!(
+                        d.angle_to(&o),
+                        Direction::from(d).angle_to(&Direction::from(o))
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn char_parsing() {
+            for (chars, expected) in [
+                ("nNuU^", Cardinal::North),
+                ("sSdDvV", Cardinal::South),
+                ("eErR>", Cardinal::East),
+                ("wWlL<", Cardinal::West),
+            ] {
+                for c in chars.chars() {
+                    assert_eq!(Cardinal::try_from(c).unwrap(), expected);
+                }
+            }
+
+            assert!(Cardinal::try_from('x').is_err());
+        }
+
+        #[test]
+        fn turning() {
+            for d in Cardinal::ALL {
+                assert_eq!(d.turn(Turn::Left), d.left());
+                assert_eq!(d.turn(Turn::Right), d.right());
+                assert_eq!(d.turn(Turn::Straight), d);
+                assert_eq!(d.turn(Turn::Back), d.opposite());
+
+                for t in [Turn::Left, Turn::Right, Turn::Straight, Turn::Back] {
+                    assert_eq!(d.turn(t).turn(t.inverse()), d);
+                }
+            }
+        }
+    }
+
+    mod turn {
+        use super::super::*;
+
+        #[test]
+        fn parsing() {
+            for v in ["Left", "left", "L", "l"] {
+                assert_eq!(Turn::from_str(v).unwrap(), Turn::Left);
+            }
+
+            for v in ["Right", "right", "R", "r"] {
+                assert_eq!(Turn::from_str(v).unwrap(), Turn::Right);
+            }
+
+            for v in ["Straight", "forward", "S", "f"] {
+                assert_eq!(Turn::from_str(v).unwrap(), Turn::Straight);
+            }
+
+            for v in ["Back", "back", "B", "b"] {
+                assert_eq!(Turn::from_str(v).unwrap(), Turn::Back);
+            }
+
+            assert!(Turn::from_str("up").is_err());
+            assert!(Turn::try_from('u').is_err());
+        }
+    }
+
+    mod hex_paths {
+        use super::super::*;
+
+        #[test]
+        fn separated() {
+            let res: Vec<HorizHexDir> = parse_hex_path("se,sw, se ,sw,sw\n").unwrap();
+            assert_eq!(
+                res,
+                vec![
+                    HorizHexDir::SouthEast,
+                    HorizHexDir::SouthWest,
+                    HorizHexDir::SouthEast,
+                    HorizHexDir::SouthWest,
+                    HorizHexDir::SouthWest,
+                ]
+            );
+
+            let res: Vec<HorizHexDir> = parse_hex_path("north,south,NE").unwrap();
+            assert_eq!(
+                res,
+                vec![
+                    HorizHexDir::North,
+                    HorizHexDir::South,
+                    HorizHexDir::NorthEast
+                ]
+            );
+
+            let res: Vec<HorizHexDir> = parse_hex_path("").unwrap();
+            assert!(res.is_empty());
+        }
+
+        #[test]
+        fn concatenated() {
+            let res: Vec<HorizHexDir> = parse_hex_path("nenesesw").unwrap();
+            assert_eq!(
+                res,
+                vec![
+                    HorizHexDir::NorthEast,
+                    HorizHexDir::NorthEast,
+                    HorizHexDir::SouthEast,
+                    HorizHexDir::SouthWest,
+                ]
+            );
+
+            let res: Vec<VertHexDir> = parse_hex_path("nwwswee").unwrap();
+            assert_eq!(
+                res,
+                vec![
+                    VertHexDir::NorthWest,
+                    VertHexDir::West,
+                    VertHexDir::SouthWest,
+                    VertHexDir::East,
+                    VertHexDir::East,
+                ]
+            );
+
+            let res: Vec<VertHexDir> =
+                parse_hex_path("sesenwnenenewseeswwswswwnenewsewsw").unwrap();
+            assert_eq!(res.len(), 20);
+
+            let res: Vec<HorizHexDir> = parse_hex_path("nsn nes").unwrap();
+            assert_eq!(
+                res,
+                vec![
+                    HorizHexDir::North,
+                    HorizHexDir::South,
+                    HorizHexDir::North,
+                    HorizHexDir::NorthEast,
+                    HorizHexDir::South,
+                ]
+            );
+        }
+
+        #[test]
+        fn errors() {
+            assert!(matches!(
+                parse_hex_path::<HorizHexDir>("ne,e"),
+                Err(AocError::ParseDirectionError(_))
+            ));
+            assert!(matches!(
+                parse_hex_path::<VertHexDir>("nesn"),
+                Err(AocError::ParseDirectionError(_))
+            ));
+            assert!(parse_hex_path::<VertHexDir>("ex").is_err());
+        }
+    }
+
+    mod movements {
+        use super::super::*;
+
+        #[test]
+        fn turns() {
+            let res: Vec<(Turn, usize)> = parse_movements("R2, L3, R10,B, F 4").unwrap();
+            assert_eq!(
+                res,
+                vec![
+                    (Turn::Right, 2),
+                    (Turn::Left, 3),
+                    (Turn::Right, 10),
+                    (Turn::Back, 1),
+                    (Turn::Straight, 4),
+                ]
+            );
+        }
+
+        #[test]
+        fn cardinals() {
+            let res: Vec<(Cardinal, usize)> = parse_movements("^>v<").unwrap();
+            assert_eq!(
+                res,
+                vec![
+                    (Cardinal::North, 1),
+                    (Cardinal::East, 1),
+                    (Cardinal::South, 1),
+                    (Cardinal::West, 1),
+                ]
+            );
+
+            let res: Vec<(Cardinal, usize)> = parse_movements("U 5\nD 3\nL 12\nR 1\n").unwrap();
+            assert_eq!(
+                res,
+                vec![
+                    (Cardinal::North, 5),
+                    (Cardinal::South, 3),
+                    (Cardinal::West, 12),
+                    (Cardinal::East, 1),
+                ]
+            );
+
+            let res: Vec<(Cardinal, usize)> = parse_movements("").unwrap();
+            assert!(res.is_empty());
+        }
+
+        #[test]
+        fn errors() {
+            let res: Result<Vec<(Cardinal, usize)>, _> = parse_movements("U 5 X 3");
+            assert!(matches!(res, Err(AocError::ParseDirectionError(_))));
+
+            let res: Result<Vec<(Turn, usize)>, _> = parse_movements("12");
+            assert!(matches!(res, Err(AocError::ParseDirectionError(_))));
+
+            let res: Result<Vec<(Turn, usize)>, _> = parse_movements("U5");
+            assert!(matches!(res, Err(AocError::ParseDirectionError(_))));
+
+            let res: Result<Vec<(Turn, usize)>, _> = parse_movements("R99999999999999999999999");
+            assert!(matches!(res, Err(AocError::ParseDirectionError(_))));
+        }
+    }
+
+    mod direction {
+        use super::super::*;
+
+        #[test]
+        fn rotations() {
+            for d in Direction::ALL {
+                assert_eq!(d.opposite().opposite(), d);
+                assert_eq!(-d, d.opposite());
+                assert_eq!(d.right_45().left_45(), d);
+                assert_eq!(d.left_45().right_45(), d);
+                assert_eq!(d.right_90().left_90(), d);
+                assert_eq!(d.right_45().right_45(), d.right_90());
+                assert_eq!(d.left_45().left_45(), d.left_90());
+                assert_eq!(d.right_90().right_90(), d.opposite());
+
+                let mut cur = d;
+                for _ in 0..8 {
+                    cur = cur.right_45();
+                }
+                assert_eq!(cur, d);
+            }
+        }
+
+        #[test]
+        fn deltas() {
+            for d in Direction::ALL {
+                let (dr, dc) = d.delta();
+                assert_eq!(d.opposite().delta(), (-dr, -dc));
+                assert!(dr.abs() <= 1 && dc.abs() <= 1 && (dr, dc) != (0, 0));
+            }
+
+            let unique: std::collections::HashSet<_> =
+                Direction::ALL.iter().map(|d| d.delta()).collect();
+            assert_eq!(unique.len(), 8);
+        }
+
+        #[test]
+        fn angles() {
+            for d in Direction::ALL {
+                assert_eq!(d.angle_to(&d), 0);
+                assert_eq!(d.angle_to(&d.right_45()), 45);
+                assert_eq!(d.angle_to(&d.right_90()), 90);
+                assert_eq!(d.angle_to(&d.opposite()), 180);
+                assert_eq!(d.angle_to(&d.left_90()), 270);
+                assert_eq!(d.angle_to(&d.left_45()), 315);
+                for o in Direction::ALL {
+                    assert_eq!((d.angle_to(&o) + o.angle_to(&d)) % 360, 0);
+                }
+            }
+        }
+
+        #[test]
+        fn display_round_trip() {
+            for d in Direction::ALL {
+                assert_eq!(Direction::from_str(&d.to_string()).unwrap(), d);
+            }
+        }
+    }
+
+    mod horiz_hex_dir {
+        use super::super::*;
+
+        #[test]
+        fn rotations() {
+            for d in HorizHexDir::ALL {
+                assert_eq!(d.opposite().opposite(), d);
+                assert_eq!(-d, d.opposite());
+                assert_eq!(d.right_60().left_60(), d);
+                assert_eq!(d.left_60().right_60(), d);
+                assert_eq!(d.right_60().right_60().right_60(), d.opposite());
+                assert_eq!(Direction::from(d.opposite()), Direction::from(d).opposite());
+
+                let mut cur = d;
+                for _ in 0..6 {
+                    cur = cur.left_60();
+                }
+                assert_eq!(cur, d);
+            }
+        }
+
+        #[test]
+        fn deltas() {
+            for d in HorizHexDir::ALL {
+                let (dq, dr) = d.delta();
+                assert_eq!(d.opposite().delta(), (-dq, -dr));
+                // every neighbor is at distance one in cube coordinates
+                assert_eq!(dq.abs() + dr.abs() + (dq + dr).abs(), 2);
+            }
+        }
+
+        #[test]
+        fn angles() {
+            for d in HorizHexDir::ALL {
+                assert_eq!(d.angle_to(&d), 0);
+                assert_eq!(d.angle_to(&d.right_60()), 60);
+                assert_eq!(d.angle_to(&d.opposite()), 180);
+                assert_eq!(d.angle_to(&d.left_60()), 300);
+                for o in HorizHexDir::ALL {
+                    assert_eq!((d.angle_to(&o) + o.angle_to(&d)) % 360, 0);
+                }
+            }
+        }
+
+        #[test]
+        fn display_round_trip() {
+            for d in HorizHexDir::ALL {
+                assert_eq!(HorizHexDir::from_str(&d.to_string()).unwrap(), d);
+            }
+        }
+    }
+
+    mod vert_hex_dir {
+        use super::super::*;
+
+        #[test]
+        fn rotations() {
+            for d in VertHexDir::ALL {
+                assert_eq!(d.opposite().opposite(), d);
+                assert_eq!(-d, d.opposite());
+                assert_eq!(d.right_60().left_60(), d);
+                assert_eq!(d.left_60().right_60(), d);
+                assert_eq!(d.right_60().right_60().right_60(), d.opposite());
+                assert_eq!(Direction::from(d.opposite()), Direction::from(d).opposite());
+
+                let mut cur = d;
+                for _ in 0..6 {
+                    cur = cur.right_60();
+                }
+                assert_eq!(cur, d);
+            }
+        }
+
+        #[test]
+        fn deltas() {
+            for d in VertHexDir::ALL {
+                let (dq, dr) = d.delta();
+                assert_eq!(d.opposite().delta(), (-dq, -dr));
+                assert_eq!(dq.abs() + dr.abs() + (dq + dr).abs(), 2);
+            }
+        }
+
+        #[test]
+        fn angles() {
+            for d in VertHexDir::ALL {
+                assert_eq!(d.angle_to(&d), 0);
+                assert_eq!(d.angle_to(&d.right_60()), 60);
+                assert_eq!(d.angle_to(&d.opposite()), 180);
+                assert_eq!(d.angle_to(&d.left_60()), 300);
+                for o in VertHexDir::ALL {
+                    assert_eq!((d.angle_to(&o) + o.angle_to(&d)) % 360, 0);
+                }
+            }
+        }
+
+        #[test]
+        fn display_round_trip() {
+            for d in VertHexDir::ALL {
+                assert_eq!(VertHexDir::from_str(&d.to_string()).unwrap(), d);
+            }
+        }
     }
 }
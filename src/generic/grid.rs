@@ -158,6 +158,7 @@ where
 {
     type Error = AocError;
 
+    #[allow(clippy::get_first)]
     fn try_from(value: Vec<Vec<T>>) -> Result<Self> {
         let rows = value.len();
         let cols = value.get(0).map(|c| c.len()).unwrap_or_default();
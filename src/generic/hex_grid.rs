@@ -0,0 +1,401 @@
+//! Grids of elements indexed by [HexLocation]
+use itertools::{Itertools, MinMaxResult};
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Display},
+    hash::Hash,
+};
+
+use super::directions::{HorizHexDir, VertHexDir};
+use super::grid::GridLike;
+use super::location::{HexLocation, HorizHexLoc, VertHexLoc};
+
+/// A dense, hexagon-shaped grid of elements indexed by [HexLocation].
+///
+/// Every location within `radius` steps of `center` has a value. As with
+/// [HexLocation], the `O` parameter is an orientation marker and must be
+/// either [HorizHexDir] or [VertHexDir].
+///
+/// # Examples
+/// ```
+/// use aoc_helpers::generic::{HexGrid, HorizHexLoc};
+/// use aoc_helpers::generic::directions::HorizHexDir;
+/// use aoc_helpers::generic::prelude::*;
+///
+/// let mut grid: HexGrid<usize, HorizHexDir> = HexGrid::new(HorizHexLoc::default(), 2, 0);
+/// assert_eq!(grid.size(), 19);
+///
+/// assert!(grid.set(&HorizHexLoc::new(2, -2), 5));
+/// assert_eq!(grid.get(&HorizHexLoc::new(2, -2)), Some(&5));
+/// assert_eq!(grid.get(&HorizHexLoc::new(3, -2)), None);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct HexGrid<T, O> {
+    pub center: HexLocation<O>,
+    pub radius: usize,
+    cells: Vec<Vec<T>>,
+}
+
+/// The default [HexGrid] is empty.
+impl<T, O> Default for HexGrid<T, O> {
+    fn default() -> Self {
+        Self {
+            center: HexLocation::default(),
+            radius: 0,
+            cells: Vec::new(),
+        }
+    }
+}
+
+impl<T, O> HexGrid<T, O>
+where
+    T: Debug + Clone,
+{
+    /// Constructs a grid with every location within `radius` of `center` set
+    /// to `value`.
+    pub fn new(center: HexLocation<O>, radius: usize, value: T) -> Self {
+        let cells = (0..=2 * radius)
+            .map(|row| vec![value.clone(); 2 * radius + 1 - row.abs_diff(radius)])
+            .collect();
+
+        Self {
+            center,
+            radius,
+            cells,
+        }
+    }
+
+    /// Yields every location in this grid along with its value, ordered by `r`
+    /// then `q`.
+    pub fn iter(&self) -> impl Iterator<Item = (HexLocation<O>, &T)> {
+        let (cq, cr) = (self.center.q, self.center.r);
+        let radius = self.radius as i64;
+        self.cells
+            .iter()
+            .enumerate()
+            .flat_map(move |(row, values)| {
+                let r = row as i64 - radius;
+                let q_start = (-radius).max(-r - radius);
+                values
+                    .iter()
+                    .enumerate()
+                    .map(move |(col, v)| (HexLocation::new(cq + q_start + col as i64, cr + r), v))
+            })
+    }
+
+    /// Yields the neighbors of `location` that are within this grid, along
+    /// with their values.
+    pub fn neighbor_values<'a>(
+        &'a self,
+        location: &HexLocation<O>,
+    ) -> impl Iterator<Item = (HexLocation<O>, &'a T)> + 'a {
+        location
+            .ring(1)
+            .filter_map(move |n| self.get(&n).map(|v| (n, v)))
+    }
+
+    /// Returns the `(row, col)` of `location` in `cells`, if it is in bounds.
+    fn index(&self, location: &HexLocation<O>) -> Option<(usize, usize)> {
+        let radius = self.radius as i64;
+        let q = location.q - self.center.q;
+        let r = location.r - self.center.r;
+        if q.abs() > radius || r.abs() > radius || (q + r).abs() > radius {
+            return None;
+        }
+
+        let q_start = (-radius).max(-r - radius);
+        Some(((r + radius) as usize, (q - q_start) as usize))
+    }
+}
+
+impl<T, O> GridLike for HexGrid<T, O>
+where
+    T: Debug + Clone,
+{
+    type Item = T;
+    type Location = HexLocation<O>;
+
+    fn get(&self, location: &Self::Location) -> Option<&Self::Item> {
+        self.index(location)
+            .and_then(|(row, col)| self.cells.get(row).and_then(|r| r.get(col)))
+    }
+
+    fn get_mut(&mut self, location: &Self::Location) -> Option<&mut Self::Item> {
+        self.index(location)
+            .and_then(move |(row, col)| self.cells.get_mut(row).and_then(|r| r.get_mut(col)))
+    }
+
+    fn size(&self) -> usize {
+        self.cells.iter().map(|r| r.len()).sum()
+    }
+}
+
+/// A sparse, unbounded grid of elements indexed by [HexLocation].
+///
+/// Unlike [HexGrid], setting a value at a location that has no value yet
+/// inserts it.
+///
+/// # Examples
+/// ```
+/// use aoc_helpers::generic::{SparseHexGrid, VertHexLoc};
+/// use aoc_helpers::generic::prelude::*;
+///
+/// let map = VertHexLoc::parse_doubled("a b\n c").unwrap();
+/// let mut grid = SparseHexGrid::from(map);
+/// assert_eq!(grid.size(), 3);
+///
+/// let far = VertHexLoc::new(100, -40);
+/// assert!(grid.set(&far, 'z'));
+/// assert_eq!(grid.get(&far), Some(&'z'));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SparseHexGrid<T, O>
+where
+    O: Eq + Hash,
+{
+    pub cells: HashMap<HexLocation<O>, T>,
+}
+
+impl<T, O> Default for SparseHexGrid<T, O>
+where
+    O: Eq + Hash,
+{
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T, O> SparseHexGrid<T, O>
+where
+    T: Debug + Clone,
+    O: Eq + Hash + Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Yields every location in this grid along with its value, in no
+    /// particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (HexLocation<O>, &T)> {
+        self.cells.iter().map(|(k, v)| (k.clone(), v))
+    }
+
+    /// Yields the neighbors of `location` that have values in this grid,
+    /// along with those values.
+    pub fn neighbor_values<'a>(
+        &'a self,
+        location: &HexLocation<O>,
+    ) -> impl Iterator<Item = (HexLocation<O>, &'a T)> + 'a {
+        location
+            .ring(1)
+            .filter_map(move |n| self.cells.get(&n).map(|v| (n, v)))
+    }
+}
+
+impl<T, O> GridLike for SparseHexGrid<T, O>
+where
+    T: Debug + Clone,
+    O: Eq + Hash + Clone,
+{
+    type Item = T;
+    type Location = HexLocation<O>;
+
+    fn get(&self, location: &Self::Location) -> Option<&Self::Item> {
+        self.cells.get(location)
+    }
+
+    fn get_mut(&mut self, location: &Self::Location) -> Option<&mut Self::Item> {
+        self.cells.get_mut(location)
+    }
+
+    /// Inserts the value for the given location. Always returns `true`.
+    fn set(&mut self, location: &Self::Location, value: Self::Item) -> bool {
+        self.cells.insert(location.clone(), value);
+        true
+    }
+
+    fn size(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+impl<T, O> From<HashMap<HexLocation<O>, T>> for SparseHexGrid<T, O>
+where
+    O: Eq + Hash,
+{
+    fn from(cells: HashMap<HexLocation<O>, T>) -> Self {
+        Self { cells }
+    }
+}
+
+/// Draws cells given by doubled `(row, col)` coordinates, leaving blanks
+/// between them. Each value is expected to display as a single char.
+fn render<'a, T, I>(cells: I) -> String
+where
+    T: Display + 'a,
+    I: Iterator<Item = ((i64, i64), &'a T)>,
+{
+    let cells: Vec<_> = cells.collect();
+    let (min_row, max_row) = match cells.iter().map(|((row, _), _)| *row).minmax() {
+        MinMaxResult::NoElements => return String::new(),
+        MinMaxResult::OneElement(v) => (v, v),
+        MinMaxResult::MinMax(min, max) => (min, max),
+    };
+    let mut min_col = cells.iter().map(|((_, col), _)| *col).min().unwrap_or(0);
+
+    // keep the parity of row + col the same after shifting, so the output
+    // can be read back as doubled coordinates
+    if (min_row + min_col) % 2 != 0 {
+        min_col -= 1;
+    }
+
+    let mut lines: Vec<Vec<String>> = vec![Vec::new(); (max_row - min_row + 1) as usize];
+    for ((row, col), value) in cells {
+        let line = &mut lines[(row - min_row) as usize];
+        let col = (col - min_col) as usize;
+        if line.len() <= col {
+            line.resize(col + 1, " ".to_string());
+        }
+        line[col] = value.to_string();
+    }
+
+    lines.iter().map(|line| line.concat()).join("\n")
+}
+
+/// Writes the given hexes via [render], using `to_doubled` to find where each
+/// one is drawn.
+fn render_hexes<'a, T, O, I>(
+    f: &mut fmt::Formatter<'_>,
+    cells: I,
+    to_doubled: fn(&HexLocation<O>) -> (i64, i64),
+) -> fmt::Result
+where
+    T: Display + 'a,
+    I: Iterator<Item = (HexLocation<O>, &'a T)>,
+{
+    write!(f, "{}", render(cells.map(|(k, v)| (to_doubled(&k), v))))
+}
+
+/// Draws the grid in doubled-height coordinates, with columns of hexes on
+/// alternating lines. This is the layout read by
+/// [`HorizHexLoc::parse_doubled`](super::HorizHexLoc::parse_doubled).
+impl<T> fmt::Display for HexGrid<T, HorizHexDir>
+where
+    T: Debug + Clone + Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_hexes(f, self.iter(), HorizHexLoc::to_doubled)
+    }
+}
+
+/// Draws the grid in doubled-width coordinates, with hexes in each row
+/// separated by blanks. This is the layout read by
+/// [`VertHexLoc::parse_doubled`](super::VertHexLoc::parse_doubled).
+impl<T> fmt::Display for HexGrid<T, VertHexDir>
+where
+    T: Debug + Clone + Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_hexes(f, self.iter(), VertHexLoc::to_doubled)
+    }
+}
+
+impl<T> fmt::Display for SparseHexGrid<T, HorizHexDir>
+where
+    T: Debug + Clone + Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_hexes(f, self.iter(), HorizHexLoc::to_doubled)
+    }
+}
+
+impl<T> fmt::Display for SparseHexGrid<T, VertHexDir>
+where
+    T: Debug + Clone + Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_hexes(f, self.iter(), VertHexLoc::to_doubled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dense() {
+        for radius in 0..5 {
+            let center = HorizHexLoc::new(3, -7);
+            let grid: HexGrid<usize, HorizHexDir> = HexGrid::new(center, radius, 1);
+            assert_eq!(grid.size(), 1 + 3 * radius * (radius + 1));
+            assert_eq!(grid.iter().count(), grid.size());
+
+            for hex in center.spiral(radius as u32 + 1) {
+                let inside = hex.distance(&center) <= radius as i64;
+                assert_eq!(grid.get(&hex).is_some(), inside);
+            }
+        }
+
+        let empty: HexGrid<usize, VertHexDir> = HexGrid::default();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn dense_mutation() {
+        let center = VertHexLoc::default();
+        let mut grid: HexGrid<i64, VertHexDir> = HexGrid::new(center, 3, 0);
+        for hex in center.spiral(3) {
+            assert!(grid.set(&hex, hex.q * 10 + hex.r));
+        }
+
+        for (hex, value) in grid.iter() {
+            assert_eq!(*value, hex.q * 10 + hex.r);
+        }
+
+        assert!(!grid.set(&VertHexLoc::new(4, 0), 1));
+    }
+
+    #[test]
+    fn neighbor_values() {
+        let center = HorizHexLoc::default();
+        let grid: HexGrid<char, HorizHexDir> = HexGrid::new(center, 1, '.');
+        assert_eq!(grid.neighbor_values(&center).count(), 6);
+        assert_eq!(grid.neighbor_values(&HorizHexLoc::new(1, 0)).count(), 3);
+
+        let mut sparse = SparseHexGrid::new();
+        sparse.set(&center, 'a');
+        sparse.set(&HorizHexLoc::new(0, 1), 'b');
+        sparse.set(&HorizHexLoc::new(5, 1), 'c');
+        let res: Vec<_> = sparse.neighbor_values(&center).collect();
+        assert_eq!(res, vec![(HorizHexLoc::new(0, 1), &'b')]);
+    }
+
+    #[test]
+    fn render_vert() {
+        let center = VertHexLoc::default();
+        let mut grid: HexGrid<char, VertHexDir> = HexGrid::new(center, 1, '.');
+        grid.set(&center, '#');
+
+        let expected = ["  . .", " . # .", "  . ."].join("\n");
+        assert_eq!(grid.to_string(), expected);
+
+        let parsed = SparseHexGrid::from(VertHexLoc::parse_doubled(&expected).unwrap());
+        assert_eq!(parsed.size(), grid.size());
+        assert_eq!(parsed.to_string(), expected);
+    }
+
+    #[test]
+    fn render_horiz() {
+        let center = HorizHexLoc::default();
+        let mut grid: HexGrid<char, HorizHexDir> = HexGrid::new(center, 1, '.');
+        grid.set(&center, '#');
+
+        let expected = ["  .", " . .", "  #", " . .", "  ."].join("\n");
+        assert_eq!(grid.to_string(), expected);
+
+        let parsed = SparseHexGrid::from(HorizHexLoc::parse_doubled(&expected).unwrap());
+        assert_eq!(parsed.to_string(), expected);
+    }
+}
@@ -0,0 +1,592 @@
+//! One-dimensional inclusive intervals and sorted sets of them, useful for
+//! puzzles involving seed ranges, sensor coverage, ID ranges and the like.
+use std::fmt::{self, Display};
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+use num::{Bounded, Num};
+
+use super::bound::{max, min};
+
+/// An inclusive interval `[start, end]`. An interval where `start > end` is
+/// considered empty.
+///
+/// Example
+/// ```
+/// use aoc_helpers::generic::Interval;
+///
+/// let a = Interval::new(3, 7);
+/// let b = Interval::new(6, 10);
+///
+/// assert_eq!(a.len(), 5);
+/// assert_eq!(a.intersection(&b), Some(Interval::new(6, 7)));
+/// assert_eq!(a.subtract(&b), vec![Interval::new(3, 5)]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Interval<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Default + Hash,
+{
+    pub start: T,
+    pub end: T,
+}
+
+impl<T> Interval<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Default + Hash,
+{
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// Builds an interval from a start and a length, as many puzzle inputs
+    /// describe ranges. A zero length produces an empty interval.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::Interval;
+    ///
+    /// assert_eq!(Interval::from_len(79_u64, 14), Interval::new(79, 92));
+    /// assert!(Interval::from_len(5_u64, 0).is_empty());
+    /// ```
+    pub fn from_len(start: T, len: T) -> Self {
+        if len == T::zero() {
+            Self::empty()
+        } else {
+            Self::new(start, start + (len - T::one()))
+        }
+    }
+
+    /// Returns an interval containing no values.
+    pub fn empty() -> Self {
+        Self::new(T::max_value(), T::min_value())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// The number of values contained in this interval.
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::zero()
+        } else {
+            self.end - self.start + T::one()
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && self.end >= value
+    }
+
+    /// Whether or not `other` lies entirely within this interval.
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && self.end >= other.end)
+    }
+
+    /// Returns the interval covered by both this interval and `other`, if any.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let res = Self::new(max(self.start, other.start), min(self.end, other.end));
+
+        if res.is_empty() {
+            None
+        } else {
+            Some(res)
+        }
+    }
+
+    /// Whether or not this interval shares at least one value with `other`.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Whether or not this interval overlaps with or sits directly next to
+    /// `other`, meaning the two could be merged into a single interval.
+    pub fn touches(&self, other: &Self) -> bool {
+        if self.is_empty() || other.is_empty() {
+            return false;
+        }
+
+        let (a, b) = if self.start <= other.start {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        // if b.start > a.end then a.end < T::max_value(), so this can't overflow
+        b.start <= a.end || a.end + T::one() == b.start
+    }
+
+    /// Returns the single interval covering both this interval and `other` if
+    /// they [touch](Self::touches).
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.touches(other) {
+            Some(Self::new(
+                min(self.start, other.start),
+                max(self.end, other.end),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the pieces of this interval not covered by `other`. There will
+    /// be at most two pieces.
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let i = match self.intersection(other) {
+            Some(i) => i,
+            None if self.is_empty() => return vec![],
+            None => return vec![*self],
+        };
+
+        let mut pieces = Vec::with_capacity(2);
+        if i.start > self.start {
+            pieces.push(Self::new(self.start, i.start - T::one()));
+        }
+        if i.end < self.end {
+            pieces.push(Self::new(i.end + T::one(), self.end));
+        }
+
+        pieces
+    }
+
+    /// Splits this interval by a mapping of the values in `source` to the
+    /// values starting at `dest`, returning the mapped portion (if any) and
+    /// the portions outside of `source` that were left unmapped.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::Interval;
+    ///
+    /// let seeds = Interval::new(79_u64, 92);
+    /// let (mapped, rest) = seeds.remap(&Interval::new(50, 97), 52);
+    ///
+    /// assert_eq!(mapped, Some(Interval::new(81, 94)));
+    /// assert!(rest.is_empty());
+    ///
+    /// let (mapped, rest) = seeds.remap(&Interval::new(90, 99), 0);
+    /// assert_eq!(mapped, Some(Interval::new(0, 2)));
+    /// assert_eq!(rest, vec![Interval::new(79, 89)]);
+    /// ```
+    pub fn remap(&self, source: &Self, dest: T) -> (Option<Self>, Vec<Self>) {
+        match self.intersection(source) {
+            Some(i) => {
+                let mapped = Self::new(
+                    dest + (i.start - source.start),
+                    dest + (i.end - source.start),
+                );
+                (Some(mapped), self.subtract(source))
+            }
+            None => (None, self.subtract(source)),
+        }
+    }
+}
+
+impl<T> fmt::Display for Interval<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Default + Hash + Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.start, self.end)
+    }
+}
+
+/// A set of values represented as sorted, disjoint, non-adjacent
+/// [Interval]s. Inserting an interval merges it with any it touches.
+///
+/// Example
+/// ```
+/// use aoc_helpers::generic::{Interval, IntervalSet};
+///
+/// let set: IntervalSet<i64> = vec![
+///     Interval::new(12, 12),
+///     Interval::new(2, 14),
+///     Interval::new(16, 24),
+///     Interval::new(-2, 2),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// assert_eq!(set.intervals(), &[Interval::new(-2, 14), Interval::new(16, 24)]);
+/// assert_eq!(set.total_len(), 26);
+/// assert_eq!(set.gaps().intervals(), &[Interval::new(15, 15)]);
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct IntervalSet<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Default + Hash,
+{
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> IntervalSet<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Default + Hash,
+{
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// The sorted, disjoint intervals making up this set.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The total number of values contained in this set.
+    pub fn total_len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::zero(), |acc, i| acc + i.len())
+    }
+
+    /// The smallest value in this set, if any.
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    /// The largest value in this set, if any.
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|i| i.end)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.find(value).is_ok()
+    }
+
+    /// Adds the given interval to this set, merging it with any intervals it
+    /// touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let first = self
+            .intervals
+            .iter()
+            .position(|i| i.touches(&interval) || i.start > interval.end)
+            .unwrap_or(self.intervals.len());
+        let count = self.intervals[first..]
+            .iter()
+            .take_while(|i| i.touches(&interval))
+            .count();
+
+        let merged = self.intervals[first..first + count]
+            .iter()
+            .fold(interval, |acc, i| acc.union(i).unwrap_or(acc));
+        self.intervals
+            .splice(first..first + count, std::iter::once(merged));
+    }
+
+    /// Removes the values in the given interval from this set.
+    pub fn remove(&mut self, interval: &Interval<T>) {
+        self.intervals = self
+            .intervals
+            .iter()
+            .flat_map(|i| i.subtract(interval))
+            .collect();
+    }
+
+    /// Returns the set of values in either this set or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut res = self.clone();
+        for i in other.iter() {
+            res.insert(*i);
+        }
+        res
+    }
+
+    /// Returns the set of values in both this set and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut res = Vec::new();
+        let (mut a, mut b) = (0, 0);
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let (x, y) = (&self.intervals[a], &other.intervals[b]);
+            if let Some(i) = x.intersection(y) {
+                res.push(i);
+            }
+
+            if x.end < y.end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+
+        Self { intervals: res }
+    }
+
+    /// Returns the set of values in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut res = self.clone();
+        for i in other.iter() {
+            res.remove(i);
+        }
+        res
+    }
+
+    /// Returns the values between the smallest and largest values of this set
+    /// that are not contained in it.
+    pub fn gaps(&self) -> Self {
+        let intervals = self
+            .intervals
+            .windows(2)
+            .map(|w| Interval::new(w[0].end + T::one(), w[1].start - T::one()))
+            .collect();
+
+        Self { intervals }
+    }
+
+    /// Returns the values in `bound` that are not contained in this set.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::{Interval, IntervalSet};
+    ///
+    /// let set: IntervalSet<u32> = vec![Interval::new(3, 5), Interval::new(9, 20)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     set.complement_within(&Interval::new(0, 10)).intervals(),
+    ///     &[Interval::new(0, 2), Interval::new(6, 8)],
+    /// );
+    /// ```
+    pub fn complement_within(&self, bound: &Interval<T>) -> Self {
+        Self::from(*bound).difference(self)
+    }
+
+    /// Applies a set of `(source, dest)` mappings to this set, where the
+    /// values in each `source` interval are moved to the values starting at
+    /// `dest`. Values not covered by any source are left as they are. The
+    /// sources are assumed not to overlap one another.
+    ///
+    /// Example
+    /// ```
+    /// use aoc_helpers::generic::{Interval, IntervalSet};
+    ///
+    /// let seeds: IntervalSet<u64> = vec![Interval::from_len(79, 14), Interval::from_len(55, 13)]
+    ///     .into_iter()
+    ///     .collect();
+    /// let mappings = vec![(Interval::from_len(98, 2), 50), (Interval::from_len(50, 48), 52)];
+    ///
+    /// assert_eq!(
+    ///     seeds.remap(&mappings).intervals(),
+    ///     &[Interval::new(57, 69), Interval::new(81, 94)],
+    /// );
+    /// ```
+    pub fn remap(&self, mappings: &[(Interval<T>, T)]) -> Self {
+        let mut res = Self::new();
+        let mut pending = self.intervals.clone();
+
+        for (source, dest) in mappings {
+            pending = pending
+                .into_iter()
+                .flat_map(|i| {
+                    let (mapped, rest) = i.remap(source, *dest);
+                    if let Some(m) = mapped {
+                        res.insert(m);
+                    }
+                    rest
+                })
+                .collect();
+        }
+
+        for i in pending {
+            res.insert(i);
+        }
+        res
+    }
+
+    /// Locates `value`, returning `Ok` with the index of the interval
+    /// containing it or `Err` with the index it would be inserted at.
+    fn find(&self, value: T) -> Result<usize, usize> {
+        self.intervals.binary_search_by(|i| {
+            if i.end < value {
+                std::cmp::Ordering::Less
+            } else if i.start > value {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+    }
+}
+
+impl<T> From<Interval<T>> for IntervalSet<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Default + Hash,
+{
+    fn from(interval: Interval<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl<T> FromIterator<Interval<T>> for IntervalSet<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Default + Hash,
+{
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for i in iter {
+            set.insert(i);
+        }
+        set
+    }
+}
+
+impl<T> fmt::Display for IntervalSet<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Default + Hash + Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self.intervals.iter().map(|i| i.to_string()).collect();
+        write!(f, "{{{}}}", parts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intervals() {
+        let a = Interval::new(0_u32, 9);
+        assert_eq!(a.len(), 10);
+        assert!(a.contains(0));
+        assert!(a.contains(9));
+        assert!(!a.contains(10));
+        assert!(a.contains_interval(&Interval::new(2, 4)));
+        assert!(!a.contains_interval(&Interval::new(2, 10)));
+
+        let e: Interval<u32> = Interval::empty();
+        assert!(e.is_empty());
+        assert_eq!(e.len(), 0);
+        assert!(!e.touches(&a));
+        assert_eq!(e.subtract(&a), vec![]);
+
+        assert!(a.touches(&Interval::new(10, 12)));
+        assert!(!a.touches(&Interval::new(11, 12)));
+        assert_eq!(a.union(&Interval::new(10, 12)), Some(Interval::new(0, 12)));
+        assert_eq!(a.union(&Interval::new(11, 12)), None);
+
+        let max = Interval::new(u32::MAX - 1, u32::MAX);
+        assert!(max.touches(&Interval::new(u32::MAX - 3, u32::MAX - 2)));
+        assert!(max.touches(&Interval::new(u32::MAX, u32::MAX)));
+    }
+
+    #[test]
+    fn subtraction() {
+        let a = Interval::new(-5_i64, 5);
+        assert_eq!(
+            a.subtract(&Interval::new(0, 0)),
+            vec![Interval::new(-5, -1), Interval::new(1, 5)]
+        );
+        assert_eq!(
+            a.subtract(&Interval::new(-10, 0)),
+            vec![Interval::new(1, 5)]
+        );
+        assert_eq!(
+            a.subtract(&Interval::new(3, 10)),
+            vec![Interval::new(-5, 2)]
+        );
+        assert_eq!(a.subtract(&Interval::new(-10, 10)), vec![]);
+        assert_eq!(a.subtract(&Interval::new(6, 10)), vec![a]);
+    }
+
+    #[test]
+    fn insertion() {
+        let mut set = IntervalSet::new();
+        set.insert(Interval::new(10_i32, 12));
+        set.insert(Interval::new(0, 2));
+        set.insert(Interval::new(5, 6));
+        set.insert(Interval::empty());
+        assert_eq!(set.intervals().len(), 3);
+        assert_eq!(set.to_string(), "{[0, 2], [5, 6], [10, 12]}");
+
+        set.insert(Interval::new(3, 4));
+        assert_eq!(set.to_string(), "{[0, 6], [10, 12]}");
+
+        set.insert(Interval::new(-3, 20));
+        assert_eq!(set.to_string(), "{[-3, 20]}");
+
+        assert!(set.contains(-3));
+        assert!(set.contains(20));
+        assert!(!set.contains(21));
+        assert_eq!(set.min(), Some(-3));
+        assert_eq!(set.max(), Some(20));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: IntervalSet<i32> = vec![Interval::new(0, 5), Interval::new(10, 15)]
+            .into_iter()
+            .collect();
+        let b: IntervalSet<i32> = vec![Interval::new(3, 11), Interval::new(14, 20)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(a.union(&b).to_string(), "{[0, 20]}");
+        assert_eq!(
+            a.intersection(&b).to_string(),
+            "{[3, 5], [10, 11], [14, 15]}"
+        );
+        assert_eq!(a.difference(&b).to_string(), "{[0, 2], [12, 13]}");
+        assert_eq!(b.difference(&a).to_string(), "{[6, 9], [16, 20]}");
+
+        // compare against a brute force check of every value
+        for v in -5..25 {
+            assert_eq!(a.union(&b).contains(v), a.contains(v) || b.contains(v));
+            assert_eq!(
+                a.intersection(&b).contains(v),
+                a.contains(v) && b.contains(v)
+            );
+            assert_eq!(
+                a.difference(&b).contains(v),
+                a.contains(v) && !b.contains(v)
+            );
+        }
+    }
+
+    #[test]
+    fn gaps() {
+        let set: IntervalSet<i32> = vec![
+            Interval::new(0, 3),
+            Interval::new(5, 5),
+            Interval::new(9, 10),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(set.gaps().to_string(), "{[4, 4], [6, 8]}");
+        assert_eq!(
+            set.complement_within(&Interval::new(-2, 12)).to_string(),
+            "{[-2, -1], [4, 4], [6, 8], [11, 12]}"
+        );
+        assert_eq!(set.total_len(), 7);
+        assert!(IntervalSet::<i32>::new().gaps().is_empty());
+    }
+
+    #[test]
+    fn remapping() {
+        let seeds: IntervalSet<u64> = vec![Interval::new(0, 9)].into_iter().collect();
+        let mappings = vec![(Interval::new(2, 3), 20), (Interval::new(8, 12), 30)];
+
+        assert_eq!(
+            seeds.remap(&mappings).to_string(),
+            "{[0, 1], [4, 7], [20, 21], [30, 31]}"
+        );
+        assert_eq!(seeds.remap(&mappings).total_len(), seeds.total_len());
+        assert_eq!(seeds.remap(&[]), seeds);
+    }
+}
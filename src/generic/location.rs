@@ -1,9 +1,12 @@
 use itertools::Itertools;
-use std::{marker::PhantomData, str::FromStr};
+use std::{collections::HashMap, marker::PhantomData, str::FromStr};
 
 use crate::error::{AocError, Result};
 
-use super::directions::{HorizHexDir, VertHexDir};
+use super::directions::{Direction, HorizHexDir, VertHexDir};
+use super::grid::Rectangular;
+
+const SQRT_3: f64 = 1.732_050_807_568_877_2;
 
 /// A `Location` specifies a pair of [usize], [usize] representing a `row` and
 /// `column` respectively. Primarily this is used to interact with [GridLike](super::grid::GridLike)
@@ -55,6 +58,7 @@ impl Location {
 
     /// Yields an iterator over the eight neighbors of this Location, ignoring
     /// locations where row/col would be negative
+    #[allow(clippy::useless_conversion)]
     pub fn neighbors(&self) -> impl Iterator<Item = Self> {
         let current_row = self.row as i64;
         let current_col = self.col as i64;
@@ -128,6 +132,108 @@ impl Location {
     pub fn east(&self) -> Option<Location> {
         Some((self.row, self.col + 1).into())
     }
+
+    pub fn north_east(&self) -> Option<Location> {
+        if self.row == 0 {
+            return None;
+        }
+
+        Some((self.row - 1, self.col + 1).into())
+    }
+
+    pub fn north_west(&self) -> Option<Location> {
+        if self.row == 0 || self.col == 0 {
+            return None;
+        }
+
+        Some((self.row - 1, self.col - 1).into())
+    }
+
+    /// Returns the neighboring [Location] in the given direction, or `None` if
+    /// the row or col would become negative. Accepts anything convertible to a
+    /// [Direction], like [Cardinal](super::directions::Cardinal).
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::Location;
+    /// use aoc_helpers::generic::directions::{Cardinal, Direction};
+    ///
+    /// let loc = Location::new(1, 1);
+    /// assert_eq!(loc.step(Cardinal::North), Some(Location::new(0, 1)));
+    /// assert_eq!(loc.step(Direction::SouthWest), Some(Location::new(2, 0)));
+    /// assert_eq!(Location::new(0, 0).step(Cardinal::West), None);
+    /// ```
+    pub fn step<D>(&self, dir: D) -> Option<Location>
+    where
+        D: Into<Direction>,
+    {
+        self.step_n(dir, 1)
+    }
+
+    /// Returns the [Location] `n` steps away in the given direction, or `None`
+    /// if the row or col would become negative (or overflow).
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::Location;
+    /// use aoc_helpers::generic::directions::{Cardinal, Direction};
+    ///
+    /// let loc = Location::new(3, 3);
+    /// assert_eq!(loc.step_n(Cardinal::East, 4), Some(Location::new(3, 7)));
+    /// assert_eq!(loc.step_n(Direction::NorthWest, 3), Some(Location::new(0, 0)));
+    /// assert_eq!(loc.step_n(Direction::NorthWest, 4), None);
+    /// ```
+    pub fn step_n<D>(&self, dir: D, n: usize) -> Option<Location>
+    where
+        D: Into<Direction>,
+    {
+        let (dr, dc) = dir.into().delta();
+
+        Some(Self::new(
+            Self::offset(self.row, dr, n)?,
+            Self::offset(self.col, dc, n)?,
+        ))
+    }
+
+    /// Like [`step`](Location::step), but additionally returns `None` if the
+    /// resulting [Location] would fall outside of the given grid.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::{Grid, Location};
+    /// use aoc_helpers::generic::directions::Cardinal;
+    ///
+    /// let grid = Grid::new(vec![vec![0; 3]; 2]);
+    /// let loc = Location::new(1, 1);
+    /// assert_eq!(loc.step_bounded(Cardinal::East, &grid), Some(Location::new(1, 2)));
+    /// assert_eq!(loc.step_bounded(Cardinal::South, &grid), None);
+    /// ```
+    pub fn step_bounded<D, G>(&self, dir: D, grid: &G) -> Option<Location>
+    where
+        D: Into<Direction>,
+        G: Rectangular,
+    {
+        self.step_n_bounded(dir, 1, grid)
+    }
+
+    /// Like [`step_n`](Location::step_n), but additionally returns `None` if
+    /// the resulting [Location] would fall outside of the given grid.
+    pub fn step_n_bounded<D, G>(&self, dir: D, n: usize, grid: &G) -> Option<Location>
+    where
+        D: Into<Direction>,
+        G: Rectangular,
+    {
+        self.step_n(dir, n)
+            .filter(|loc| loc.row < grid.rows() && loc.col < grid.cols())
+    }
+
+    fn offset(val: usize, delta: i64, n: usize) -> Option<usize> {
+        match delta {
+            d if d < 0 => val.checked_sub(n),
+            d if d > 0 => val.checked_add(n),
+            _ => Some(val),
+        }
+    }
 }
 
 impl From<(usize, usize)> for Location {
@@ -213,6 +319,10 @@ impl<T> From<(i64, i64)> for HexLocation<T> {
 }
 
 impl<T> HexLocation<T> {
+    /// The six axial offsets to neighboring hexes, shared by both orientations.
+    /// Each offset is a 60 degree rotation of the previous one.
+    const AXIAL_DIRS: [(i64, i64); 6] = [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)];
+
     /// Construct a new [HexLocation]. Equivalent to `(q, r).into()`
     ///
     /// # Examples
@@ -266,6 +376,255 @@ impl<T> HexLocation<T> {
             + (self.r - other.r).abs())
             / 2
     }
+
+    /// Yields the hexes exactly `radius` steps away from this location, walking
+    /// around the ring one side at a time. A `radius` of zero yields only this
+    /// location.
+    ///
+    /// See [rings](https://www.redblobgames.com/grids/hexagons/#rings).
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::{HorizHexLoc, VertHexLoc};
+    ///
+    /// let center = HorizHexLoc::new(1, -2);
+    /// assert_eq!(center.ring(2).count(), 12);
+    /// assert!(center.ring(2).all(|hex| hex.distance(&center) == 2));
+    ///
+    /// assert_eq!(VertHexLoc::default().ring(0).collect::<Vec<_>>(), vec![VertHexLoc::default()]);
+    /// ```
+    pub fn ring(&self, radius: u32) -> impl Iterator<Item = Self> {
+        let (q, r) = (self.q, self.r);
+        let radius = radius as i64;
+        let sides = if radius == 0 { 1 } else { 6 };
+        let len = radius.max(1);
+
+        (0..sides).flat_map(move |side| {
+            let (cq, cr) = Self::AXIAL_DIRS[(side + 4) % 6];
+            let (dq, dr) = Self::AXIAL_DIRS[side];
+            (0..len).map(move |step| {
+                Self::new(q + cq * radius + dq * step, r + cr * radius + dr * step)
+            })
+        })
+    }
+
+    /// Yields this location followed by every ring out to and including
+    /// `radius`, in order of increasing distance.
+    ///
+    /// See [spiral rings](https://www.redblobgames.com/grids/hexagons/#rings-spiral).
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::HorizHexLoc;
+    ///
+    /// let center = HorizHexLoc::default();
+    /// let spiral: Vec<_> = center.spiral(2).collect();
+    /// assert_eq!(spiral.len(), 19);
+    /// assert_eq!(spiral[0], center);
+    /// assert!(spiral[1..7].iter().all(|hex| hex.distance(&center) == 1));
+    /// ```
+    pub fn spiral(&self, radius: u32) -> impl Iterator<Item = Self> {
+        let center = Self::new(self.q, self.r);
+        (0..=radius).flat_map(move |ring| center.ring(ring))
+    }
+
+    /// Yields every hex within `n` steps of this location (inclusive), ordered
+    /// by `q` then `r`.
+    ///
+    /// See [movement range](https://www.redblobgames.com/grids/hexagons/#range-coordinate).
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::VertHexLoc;
+    ///
+    /// let center = VertHexLoc::new(3, 3);
+    /// assert_eq!(center.within_range(1).count(), 7);
+    /// assert!(center.within_range(3).all(|hex| hex.distance(&center) <= 3));
+    /// ```
+    pub fn within_range(&self, n: u32) -> impl Iterator<Item = Self> {
+        let (cq, cr) = (self.q, self.r);
+        let n = n as i64;
+        (-n..=n).flat_map(move |q| {
+            ((-n).max(-q - n)..=n.min(-q + n)).map(move |r| Self::new(cq + q, cr + r))
+        })
+    }
+
+    /// Yields every hex that is both within `n` steps of this location and
+    /// within `m` steps of `other`, ordered by `q` then `r`.
+    ///
+    /// See [intersecting ranges](https://www.redblobgames.com/grids/hexagons/#range-intersection).
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::HorizHexLoc;
+    ///
+    /// let a = HorizHexLoc::new(0, 0);
+    /// let b = HorizHexLoc::new(2, -1);
+    ///
+    /// let overlap: Vec<_> = a.range_intersection(1, &b, 1).collect();
+    /// assert_eq!(overlap, vec![HorizHexLoc::new(1, -1), HorizHexLoc::new(1, 0)]);
+    ///
+    /// assert_eq!(a.range_intersection(1, &HorizHexLoc::new(5, 0), 1).count(), 0);
+    /// ```
+    pub fn range_intersection(&self, n: u32, other: &Self, m: u32) -> impl Iterator<Item = Self> {
+        let (n, m) = (n as i64, m as i64);
+        let q_min = (self.q - n).max(other.q - m);
+        let q_max = (self.q + n).min(other.q + m);
+        let r_min = (self.r - n).max(other.r - m);
+        let r_max = (self.r + n).min(other.r + m);
+        let s_min = (self.s() - n).max(other.s() - m);
+        let s_max = (self.s() + n).min(other.s() + m);
+
+        (q_min..=q_max).flat_map(move |q| {
+            (r_min.max(-q - s_max)..=r_max.min(-q - s_min)).map(move |r| Self::new(q, r))
+        })
+    }
+
+    /// Returns the hexes on the straight line from this location to `other`,
+    /// inclusive of both ends.
+    ///
+    /// See [line drawing](https://www.redblobgames.com/grids/hexagons/#line-drawing).
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::HorizHexLoc;
+    ///
+    /// let line = HorizHexLoc::new(0, 0).line_to(&HorizHexLoc::new(3, -1));
+    /// assert_eq!(line.len(), 4);
+    /// assert_eq!(line[0], HorizHexLoc::new(0, 0));
+    /// assert_eq!(line[3], HorizHexLoc::new(3, -1));
+    /// ```
+    pub fn line_to(&self, other: &Self) -> Vec<Self> {
+        let n = self.distance(other);
+        if n == 0 {
+            return vec![Self::new(self.q, self.r)];
+        }
+
+        // nudge the endpoints so that lines running exactly along a hex edge
+        // are consistently rounded to the same side
+        let (q0, r0) = (self.q as f64 + 1e-6, self.r as f64 + 1e-6);
+        let (q1, r1) = (other.q as f64 + 1e-6, other.r as f64 + 1e-6);
+
+        (0..=n)
+            .map(|i| {
+                let t = i as f64 / n as f64;
+                Self::round(q0 + (q1 - q0) * t, r0 + (r1 - r0) * t)
+            })
+            .collect()
+    }
+
+    /// Rotates this location 60 degrees clockwise about `center`.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::HorizHexLoc;
+    /// use aoc_helpers::generic::directions::HorizHexDir;
+    ///
+    /// let center = HorizHexLoc::new(2, 2);
+    /// let north = center.get_neighbor(&HorizHexDir::North);
+    /// assert_eq!(north.rotate_right(&center), center.get_neighbor(&HorizHexDir::NorthEast));
+    /// ```
+    pub fn rotate_right(&self, center: &Self) -> Self {
+        let (_, r, s) = self.relative_to(center);
+        center.offset_by(-r, -s)
+    }
+
+    /// Rotates this location 60 degrees counter-clockwise about `center`.
+    pub fn rotate_left(&self, center: &Self) -> Self {
+        let (q, _, s) = self.relative_to(center);
+        center.offset_by(-s, -q)
+    }
+
+    /// Reflects this location across the `q` axis passing through `center`,
+    /// keeping `q` fixed and swapping `r` and `s`.
+    pub fn reflect_q(&self, center: &Self) -> Self {
+        let (q, _, s) = self.relative_to(center);
+        center.offset_by(q, s)
+    }
+
+    /// Reflects this location across the `r` axis passing through `center`,
+    /// keeping `r` fixed and swapping `q` and `s`.
+    pub fn reflect_r(&self, center: &Self) -> Self {
+        let (_, r, s) = self.relative_to(center);
+        center.offset_by(s, r)
+    }
+
+    /// Reflects this location across the `s` axis passing through `center`,
+    /// keeping `s` fixed and swapping `q` and `r`.
+    pub fn reflect_s(&self, center: &Self) -> Self {
+        let (q, r, _) = self.relative_to(center);
+        center.offset_by(r, q)
+    }
+
+    /// Returns the cube coordinates of this location relative to `center`.
+    fn relative_to(&self, center: &Self) -> (i64, i64, i64) {
+        (self.q - center.q, self.r - center.r, self.s() - center.s())
+    }
+
+    fn offset_by(&self, dq: i64, dr: i64) -> Self {
+        Self::new(self.q + dq, self.r + dr)
+    }
+
+    /// Yields the `(row, col, char)` of every non-whitespace char in `input`.
+    fn text_cells(input: &str) -> impl Iterator<Item = (i64, i64, char)> + '_ {
+        input.lines().enumerate().flat_map(|(row, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|(_, ch)| !ch.is_whitespace())
+                .map(move |(col, ch)| (row as i64, col as i64, ch))
+        })
+    }
+
+    /// Collects `(location, char)` pairs into a map, failing on the first
+    /// location that could not be determined.
+    fn collect_cells<I>(cells: I) -> Result<HashMap<Self, char>>
+    where
+        I: Iterator<Item = (Option<Self>, i64, i64, char)>,
+        T: Eq + std::hash::Hash,
+    {
+        cells
+            .map(|(loc, row, col, ch)| {
+                loc.map(|loc| (loc, ch)).ok_or_else(|| {
+                    AocError::ParseLocationError(format!(
+                        "'{}' at row {}, col {} is not a valid doubled coordinate",
+                        ch, row, col
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Rounds fractional axial coordinates to the nearest hex.
+    fn round(q: f64, r: f64) -> Self {
+        let s = -q - r;
+        let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        }
+
+        Self::new(rq as i64, rr as i64)
+    }
+}
+
+/// Selects which rows or columns are shoved by half a hex in an offset
+/// layout. See [`HorizHexLoc::to_offset`] and [`VertHexLoc::to_offset`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum OffsetParity {
+    Odd,
+    Even,
+}
+
+impl OffsetParity {
+    fn shift(&self, v: i64) -> i64 {
+        match self {
+            Self::Odd => -(v & 1),
+            Self::Even => v & 1,
+        }
+    }
 }
 
 /// A [HexLocation] where North and South are flat faces
@@ -309,14 +668,8 @@ impl HexLocation<HorizHexDir> {
     /// assert_eq!(loc.get_neighbor(&HorizHexDir::SouthEast), HorizHexLoc::from((2, 1)));
     /// ```
     pub fn get_neighbor(&self, dir: &HorizHexDir) -> Self {
-        match dir {
-            HorizHexDir::North => (self.q, self.r - 1).into(),
-            HorizHexDir::NorthEast => (self.q + 1, self.r - 1).into(),
-            HorizHexDir::NorthWest => (self.q - 1, self.r).into(),
-            HorizHexDir::South => (self.q, self.r + 1).into(),
-            HorizHexDir::SouthEast => (self.q + 1, self.r).into(),
-            HorizHexDir::SouthWest => (self.q - 1, self.r + 1).into(),
-        }
+        let (dq, dr) = dir.delta();
+        (self.q + dq, self.r + dr).into()
     }
 
     /// Yields an iterator over the neighbors of this location.
@@ -327,6 +680,173 @@ impl HexLocation<HorizHexDir> {
             .iter()
             .map(move |dir| self.get_neighbor(dir))
     }
+
+    /// Follows the given path from this location, returning the location at
+    /// the end of the path and the furthest distance from this location
+    /// reached along the way.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::HorizHexLoc;
+    /// use aoc_helpers::generic::directions::{parse_hex_path, HorizHexDir};
+    ///
+    /// let path: Vec<HorizHexDir> = parse_hex_path("ne,ne,sw,sw").unwrap();
+    /// let (end, furthest) = HorizHexLoc::default().walk(&path);
+    /// assert_eq!(end, HorizHexLoc::default());
+    /// assert_eq!(furthest, 2);
+    /// ```
+    pub fn walk(&self, path: &[HorizHexDir]) -> (Self, i64) {
+        path.iter().fold((*self, 0), |(cur, furthest), dir| {
+            let next = cur.get_neighbor(dir);
+            (next, furthest.max(next.distance(self)))
+        })
+    }
+
+    /// Reflects this location across the east-west line through `center`, so
+    /// that North becomes South.
+    pub fn mirror_north_south(&self, center: &Self) -> Self {
+        self.reflect_q(center)
+    }
+
+    /// Reflects this location across the north-south line through `center`,
+    /// so that NorthEast becomes NorthWest.
+    pub fn mirror_east_west(&self, center: &Self) -> Self {
+        let (q, r, _) = self.relative_to(center);
+        center.offset_by(-q, q + r)
+    }
+
+    /// Returns the `(row, col)` of this location in an offset layout where
+    /// every other column is shoved down by half a hex. `Odd` shoves odd
+    /// columns and `Even` shoves even columns.
+    ///
+    /// See [offset coordinates](https://www.redblobgames.com/grids/hexagons/#coordinates-offset).
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::HorizHexLoc;
+    /// use aoc_helpers::generic::location::OffsetParity;
+    ///
+    /// let hex = HorizHexLoc::new(1, 1);
+    /// assert_eq!(hex.to_offset(OffsetParity::Odd), (1, 1));
+    /// assert_eq!(hex.to_offset(OffsetParity::Even), (2, 1));
+    /// assert_eq!(HorizHexLoc::from_offset(2, 1, OffsetParity::Even), hex);
+    /// ```
+    pub fn to_offset(&self, parity: OffsetParity) -> (i64, i64) {
+        let col = self.q;
+        let row = self.r + (self.q + parity.shift(self.q)) / 2;
+        (row, col)
+    }
+
+    /// Constructs a location from a `(row, col)` in an offset layout. See
+    /// [`to_offset`](HorizHexLoc::to_offset).
+    pub fn from_offset(row: i64, col: i64, parity: OffsetParity) -> Self {
+        Self::new(col, row - (col + parity.shift(col)) / 2)
+    }
+
+    /// Returns the `(row, col)` of this location in doubled-height
+    /// coordinates, where each column is numbered normally and rows are
+    /// doubled so that vertically adjacent hexes differ by two.
+    ///
+    /// See [doubled coordinates](https://www.redblobgames.com/grids/hexagons/#coordinates-doubled).
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::HorizHexLoc;
+    /// use aoc_helpers::generic::directions::HorizHexDir;
+    ///
+    /// let hex = HorizHexLoc::new(1, 1);
+    /// assert_eq!(hex.to_doubled(), (3, 1));
+    /// assert_eq!(hex.get_neighbor(&HorizHexDir::South).to_doubled(), (5, 1));
+    /// assert_eq!(HorizHexLoc::from_doubled(3, 1), Some(hex));
+    /// ```
+    pub fn to_doubled(&self) -> (i64, i64) {
+        (2 * self.r + self.q, self.q)
+    }
+
+    /// Constructs a location from doubled-height `(row, col)` coordinates.
+    /// Returns `None` if `row + col` is odd, as no hex exists there.
+    pub fn from_doubled(row: i64, col: i64) -> Option<Self> {
+        if (row + col) % 2 != 0 {
+            return None;
+        }
+
+        Some(Self::new(col, (row - col) / 2))
+    }
+
+    /// Parses a text map in an offset layout where every char is a cell, the
+    /// line number is the row, and the char index is the column. Whitespace is
+    /// ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::HorizHexLoc;
+    /// use aoc_helpers::generic::location::OffsetParity;
+    ///
+    /// let map = HorizHexLoc::parse_offset("abc\ndef", OffsetParity::Odd);
+    /// assert_eq!(map.len(), 6);
+    /// assert_eq!(map[&HorizHexLoc::from_offset(1, 2, OffsetParity::Odd)], 'f');
+    /// ```
+    pub fn parse_offset(input: &str, parity: OffsetParity) -> HashMap<Self, char> {
+        Self::text_cells(input)
+            .map(|(row, col, ch)| (Self::from_offset(row, col, parity), ch))
+            .collect()
+    }
+
+    /// Parses a text map in doubled-height coordinates, where the line number
+    /// is the doubled row and the char index is the column. Each column's
+    /// hexes must be on alternating lines, with whitespace in between.
+    ///
+    /// Note that leading whitespace is significant, so input should not be
+    /// trimmed.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::HorizHexLoc;
+    /// use aoc_helpers::generic::directions::HorizHexDir;
+    ///
+    /// let map = HorizHexLoc::parse_doubled("a c\n b\nd e").unwrap();
+    /// let b = HorizHexLoc::from_doubled(1, 1).unwrap();
+    /// assert_eq!(map[&b], 'b');
+    /// assert_eq!(map[&b.get_neighbor(&HorizHexDir::NorthWest)], 'a');
+    /// assert_eq!(map[&b.get_neighbor(&HorizHexDir::SouthEast)], 'e');
+    ///
+    /// assert!(HorizHexLoc::parse_doubled("ab").is_err());
+    /// ```
+    pub fn parse_doubled(input: &str) -> Result<HashMap<Self, char>> {
+        Self::collect_cells(
+            Self::text_cells(input)
+                .map(|(row, col, ch)| (Self::from_doubled(row, col), row, col, ch)),
+        )
+    }
+
+    /// Returns the `(x, y)` pixel coordinates of the center of this hex, where
+    /// `size` is the distance from the center to a corner and `y` increases
+    /// southward.
+    ///
+    /// See [hex to pixel](https://www.redblobgames.com/grids/hexagons/#hex-to-pixel).
+    pub fn to_pixel(&self, size: f64) -> (f64, f64) {
+        let (q, r) = (self.q as f64, self.r as f64);
+        let x = size * 1.5 * q;
+        let y = size * (SQRT_3 / 2.0 * q + SQRT_3 * r);
+        (x, y)
+    }
+
+    /// Returns the hex containing the given pixel coordinates. The inverse of
+    /// [`to_pixel`](HorizHexLoc::to_pixel).
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::HorizHexLoc;
+    ///
+    /// let hex = HorizHexLoc::new(-3, 5);
+    /// let (x, y) = hex.to_pixel(10.0);
+    /// assert_eq!(HorizHexLoc::from_pixel(x + 2.0, y - 3.0, 10.0), hex);
+    /// ```
+    pub fn from_pixel(x: f64, y: f64, size: f64) -> Self {
+        let q = (2.0 / 3.0 * x) / size;
+        let r = (-1.0 / 3.0 * x + SQRT_3 / 3.0 * y) / size;
+        Self::round(q, r)
+    }
 }
 
 /// A [HexLocation] where West and East are flat faces
@@ -376,14 +896,8 @@ impl HexLocation<VertHexDir> {
     /// assert_eq!(loc.get_neighbor(&VertHexDir::SouthEast), VertHexLoc::from((1, 2)));
     /// ```
     pub fn get_neighbor(&self, dir: &VertHexDir) -> Self {
-        match dir {
-            VertHexDir::East => (self.q + 1, self.r).into(),
-            VertHexDir::NorthEast => (self.q + 1, self.r - 1).into(),
-            VertHexDir::SouthEast => (self.q, self.r + 1).into(),
-            VertHexDir::West => (self.q - 1, self.r).into(),
-            VertHexDir::NorthWest => (self.q, self.r - 1).into(),
-            VertHexDir::SouthWest => (self.q - 1, self.r + 1).into(),
-        }
+        let (dq, dr) = dir.delta();
+        (self.q + dq, self.r + dr).into()
     }
 
     /// Yields an iterator over the neighbors of this location.
@@ -394,9 +908,177 @@ impl HexLocation<VertHexDir> {
             .iter()
             .map(move |dir| self.get_neighbor(dir))
     }
+
+    /// Follows the given path from this location, returning the location at
+    /// the end of the path and the furthest distance from this location
+    /// reached along the way.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::VertHexLoc;
+    /// use aoc_helpers::generic::directions::{parse_hex_path, VertHexDir};
+    ///
+    /// let path: Vec<VertHexDir> = parse_hex_path("esenee").unwrap();
+    /// let (end, furthest) = VertHexLoc::default().walk(&path);
+    /// assert_eq!(end, VertHexLoc::new(3, 0));
+    /// assert_eq!(furthest, 3);
+    /// ```
+    pub fn walk(&self, path: &[VertHexDir]) -> (Self, i64) {
+        path.iter().fold((*self, 0), |(cur, furthest), dir| {
+            let next = cur.get_neighbor(dir);
+            (next, furthest.max(next.distance(self)))
+        })
+    }
+
+    /// Reflects this location across the east-west line through `center`, so
+    /// that NorthEast becomes SouthEast.
+    pub fn mirror_north_south(&self, center: &Self) -> Self {
+        let (_, r, s) = self.relative_to(center);
+        center.offset_by(-s, -r)
+    }
+
+    /// Reflects this location across the north-south line through `center`,
+    /// so that East becomes West.
+    pub fn mirror_east_west(&self, center: &Self) -> Self {
+        self.reflect_r(center)
+    }
+
+    /// Returns the `(row, col)` of this location in an offset layout where
+    /// every other row is shoved right by half a hex. `Odd` shoves odd rows
+    /// and `Even` shoves even rows.
+    ///
+    /// See [offset coordinates](https://www.redblobgames.com/grids/hexagons/#coordinates-offset).
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::VertHexLoc;
+    /// use aoc_helpers::generic::location::OffsetParity;
+    ///
+    /// let hex = VertHexLoc::new(1, 1);
+    /// assert_eq!(hex.to_offset(OffsetParity::Odd), (1, 1));
+    /// assert_eq!(hex.to_offset(OffsetParity::Even), (1, 2));
+    /// assert_eq!(VertHexLoc::from_offset(1, 2, OffsetParity::Even), hex);
+    /// ```
+    pub fn to_offset(&self, parity: OffsetParity) -> (i64, i64) {
+        let col = self.q + (self.r + parity.shift(self.r)) / 2;
+        (self.r, col)
+    }
+
+    /// Constructs a location from a `(row, col)` in an offset layout. See
+    /// [`to_offset`](VertHexLoc::to_offset).
+    pub fn from_offset(row: i64, col: i64, parity: OffsetParity) -> Self {
+        Self::new(col - (row + parity.shift(row)) / 2, row)
+    }
+
+    /// Returns the `(row, col)` of this location in doubled-width
+    /// coordinates, where each row is numbered normally and columns are
+    /// doubled so that horizontally adjacent hexes differ by two.
+    ///
+    /// See [doubled coordinates](https://www.redblobgames.com/grids/hexagons/#coordinates-doubled).
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::VertHexLoc;
+    /// use aoc_helpers::generic::directions::VertHexDir;
+    ///
+    /// let hex = VertHexLoc::new(1, 1);
+    /// assert_eq!(hex.to_doubled(), (1, 3));
+    /// assert_eq!(hex.get_neighbor(&VertHexDir::East).to_doubled(), (1, 5));
+    /// assert_eq!(VertHexLoc::from_doubled(1, 3), Some(hex));
+    /// ```
+    pub fn to_doubled(&self) -> (i64, i64) {
+        (self.r, 2 * self.q + self.r)
+    }
+
+    /// Constructs a location from doubled-width `(row, col)` coordinates.
+    /// Returns `None` if `row + col` is odd, as no hex exists there.
+    pub fn from_doubled(row: i64, col: i64) -> Option<Self> {
+        if (row + col) % 2 != 0 {
+            return None;
+        }
+
+        Some(Self::new((col - row) / 2, row))
+    }
+
+    /// Parses a text map in an offset layout where every char is a cell, the
+    /// line number is the row, and the char index is the column. Whitespace is
+    /// ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::VertHexLoc;
+    /// use aoc_helpers::generic::location::OffsetParity;
+    ///
+    /// let map = VertHexLoc::parse_offset("abc\ndef", OffsetParity::Even);
+    /// assert_eq!(map.len(), 6);
+    /// assert_eq!(map[&VertHexLoc::from_offset(1, 0, OffsetParity::Even)], 'd');
+    /// ```
+    pub fn parse_offset(input: &str, parity: OffsetParity) -> HashMap<Self, char> {
+        Self::text_cells(input)
+            .map(|(row, col, ch)| (Self::from_offset(row, col, parity), ch))
+            .collect()
+    }
+
+    /// Parses a text map in doubled-width coordinates, the way pointy-topped
+    /// hex maps are usually drawn, where the line number is the row and the
+    /// char index is the doubled column. Hexes in a row must be separated by
+    /// whitespace.
+    ///
+    /// Note that leading whitespace is significant, so input should not be
+    /// trimmed.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::VertHexLoc;
+    /// use aoc_helpers::generic::directions::VertHexDir;
+    ///
+    /// let map = VertHexLoc::parse_doubled("a b c\n d e\nf g h").unwrap();
+    /// let d = VertHexLoc::from_doubled(1, 1).unwrap();
+    /// assert_eq!(map[&d], 'd');
+    /// assert_eq!(map[&d.get_neighbor(&VertHexDir::NorthWest)], 'a');
+    /// assert_eq!(map[&d.get_neighbor(&VertHexDir::SouthEast)], 'g');
+    ///
+    /// assert!(VertHexLoc::parse_doubled("ab").is_err());
+    /// ```
+    pub fn parse_doubled(input: &str) -> Result<HashMap<Self, char>> {
+        Self::collect_cells(
+            Self::text_cells(input)
+                .map(|(row, col, ch)| (Self::from_doubled(row, col), row, col, ch)),
+        )
+    }
+
+    /// Returns the `(x, y)` pixel coordinates of the center of this hex, where
+    /// `size` is the distance from the center to a corner and `y` increases
+    /// southward.
+    ///
+    /// See [hex to pixel](https://www.redblobgames.com/grids/hexagons/#hex-to-pixel).
+    pub fn to_pixel(&self, size: f64) -> (f64, f64) {
+        let (q, r) = (self.q as f64, self.r as f64);
+        let x = size * (SQRT_3 * q + SQRT_3 / 2.0 * r);
+        let y = size * 1.5 * r;
+        (x, y)
+    }
+
+    /// Returns the hex containing the given pixel coordinates. The inverse of
+    /// [`to_pixel`](VertHexLoc::to_pixel).
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::VertHexLoc;
+    ///
+    /// let hex = VertHexLoc::new(4, -2);
+    /// let (x, y) = hex.to_pixel(10.0);
+    /// assert_eq!(VertHexLoc::from_pixel(x - 3.0, y + 2.0, 10.0), hex);
+    /// ```
+    pub fn from_pixel(x: f64, y: f64, size: f64) -> Self {
+        let q = (SQRT_3 / 3.0 * x - 1.0 / 3.0 * y) / size;
+        let r = (2.0 / 3.0 * y) / size;
+        Self::round(q, r)
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::useless_conversion)]
 mod tests {
     mod location {
         use std::collections::HashSet;
@@ -540,5 +1222,454 @@ mod tests {
             let l = Location::new(2, 0);
             assert_eq!(l.west(), None);
         }
+
+        #[test]
+        fn north_east() {
+            let l = Location::new(2, 2);
+            assert_eq!(l.north_east(), Some(Location::new(1, 3)));
+
+            let l = Location::new(0, 2);
+            assert_eq!(l.north_east(), None);
+        }
+
+        #[test]
+        fn north_west() {
+            let l = Location::new(2, 2);
+            assert_eq!(l.north_west(), Some(Location::new(1, 1)));
+
+            let l = Location::new(0, 2);
+            assert_eq!(l.north_west(), None);
+
+            let l = Location::new(2, 0);
+            assert_eq!(l.north_west(), None);
+        }
+
+        #[test]
+        fn step() {
+            use crate::generic::directions::Cardinal;
+
+            let l = Location::new(2, 2);
+            assert_eq!(l.step(Cardinal::North), l.north());
+            assert_eq!(l.step(Cardinal::South), l.south());
+            assert_eq!(l.step(Cardinal::East), l.east());
+            assert_eq!(l.step(Cardinal::West), l.west());
+            assert_eq!(l.step(Direction::NorthEast), l.north_east());
+            assert_eq!(l.step(Direction::NorthWest), l.north_west());
+            assert_eq!(l.step(Direction::SouthEast), l.south_east());
+            assert_eq!(l.step(Direction::SouthWest), l.south_west());
+
+            let l = Location::new(0, 0);
+            assert_eq!(l.step(Cardinal::North), None);
+            assert_eq!(l.step(Cardinal::West), None);
+            assert_eq!(l.step(Direction::SouthWest), None);
+        }
+
+        #[test]
+        fn step_n() {
+            use crate::generic::directions::Cardinal;
+
+            let l = Location::new(2, 3);
+            assert_eq!(l.step_n(Cardinal::North, 2), Some(Location::new(0, 3)));
+            assert_eq!(l.step_n(Cardinal::North, 3), None);
+            assert_eq!(l.step_n(Cardinal::West, 3), Some(Location::new(2, 0)));
+            assert_eq!(l.step_n(Direction::SouthEast, 5), Some(Location::new(7, 8)));
+            assert_eq!(l.step_n(Direction::NorthWest, 0), Some(l));
+            assert_eq!(Location::new(0, usize::MAX).step_n(Cardinal::East, 1), None);
+        }
+
+        #[test]
+        fn step_bounded() {
+            use crate::generic::directions::Cardinal;
+            use crate::generic::Grid;
+
+            let grid = Grid::new(vec![vec![0; 4]; 3]);
+            let l = Location::new(1, 1);
+            assert_eq!(
+                l.step_bounded(Cardinal::South, &grid),
+                Some(Location::new(2, 1))
+            );
+            assert_eq!(l.step_n_bounded(Cardinal::South, 2, &grid), None);
+            assert_eq!(
+                l.step_n_bounded(Cardinal::East, 2, &grid),
+                Some(Location::new(1, 3))
+            );
+            assert_eq!(l.step_n_bounded(Cardinal::East, 3, &grid), None);
+            assert_eq!(
+                l.step_bounded(Direction::NorthWest, &grid),
+                Some(Location::new(0, 0))
+            );
+            assert_eq!(l.step_n_bounded(Direction::NorthWest, 2, &grid), None);
+        }
+    }
+
+    mod hex_location {
+        use std::collections::HashSet;
+
+        use super::super::*;
+
+        fn check_rings<T>(center: HexLocation<T>)
+        where
+            T: Copy + Eq + std::hash::Hash + std::fmt::Debug,
+        {
+            assert_eq!(center.ring(0).collect::<Vec<_>>(), vec![center]);
+
+            for radius in 1..6 {
+                let ring: Vec<_> = center.ring(radius).collect();
+                let unique: HashSet<_> = ring.iter().copied().collect();
+                assert_eq!(ring.len(), 6 * radius as usize);
+                assert_eq!(unique.len(), ring.len());
+                assert!(ring.iter().all(|h| h.distance(&center) == radius as i64));
+
+                // consecutive hexes in a ring are adjacent, including the wrap
+                for (a, b) in ring.iter().zip(ring.iter().cycle().skip(1)) {
+                    assert_eq!(a.distance(b), 1);
+                }
+            }
+        }
+
+        fn check_ranges<T>(center: HexLocation<T>)
+        where
+            T: Copy + Eq + std::hash::Hash + std::fmt::Debug,
+        {
+            for radius in 0..6 {
+                let spiral: Vec<_> = center.spiral(radius).collect();
+                let range: HashSet<_> = center.within_range(radius).collect();
+                let expected = 1 + 3 * radius as usize * (radius as usize + 1);
+
+                assert_eq!(spiral.len(), expected);
+                assert_eq!(range.len(), expected);
+                assert_eq!(spiral.iter().copied().collect::<HashSet<_>>(), range);
+                assert!(spiral
+                    .windows(2)
+                    .all(|w| w[0].distance(&center) <= w[1].distance(&center)));
+            }
+        }
+
+        #[test]
+        fn rings() {
+            check_rings(HorizHexLoc::default());
+            check_rings(HorizHexLoc::new(-3, 7));
+            check_rings(VertHexLoc::default());
+            check_rings(VertHexLoc::new(4, -2));
+        }
+
+        #[test]
+        fn ranges() {
+            check_ranges(HorizHexLoc::default());
+            check_ranges(HorizHexLoc::new(-3, 7));
+            check_ranges(VertHexLoc::default());
+            check_ranges(VertHexLoc::new(4, -2));
+        }
+
+        #[test]
+        fn range_intersection() {
+            let a = HorizHexLoc::new(0, 0);
+            for (other, n, m) in [
+                (HorizHexLoc::new(2, -1), 2, 1),
+                (HorizHexLoc::new(-3, 3), 3, 2),
+                (HorizHexLoc::new(0, 0), 1, 4),
+                (HorizHexLoc::new(10, 0), 2, 2),
+            ] {
+                let expected: HashSet<_> = a
+                    .within_range(n)
+                    .filter(|h| h.distance(&other) <= m as i64)
+                    .collect();
+                let res: Vec<_> = a.range_intersection(n, &other, m).collect();
+                assert_eq!(res.len(), expected.len());
+                assert_eq!(res.into_iter().collect::<HashSet<_>>(), expected);
+            }
+
+            let a = VertHexLoc::new(1, 1);
+            let b = VertHexLoc::new(3, 0);
+            let expected: HashSet<_> = b.within_range(2).filter(|h| h.distance(&a) <= 2).collect();
+            assert_eq!(
+                a.range_intersection(2, &b, 2).collect::<HashSet<_>>(),
+                expected
+            );
+        }
+
+        #[test]
+        fn lines() {
+            let a = HorizHexLoc::new(-2, 1);
+            for b in a.spiral(5) {
+                let line = a.line_to(&b);
+                assert_eq!(line.len() as i64, a.distance(&b) + 1);
+                assert_eq!(line.first(), Some(&a));
+                assert_eq!(line.last(), Some(&b));
+                assert!(line.windows(2).all(|w| w[0].distance(&w[1]) == 1));
+            }
+
+            // straight lines along a direction hit every hex on the way
+            let a = VertHexLoc::default();
+            let b = VertHexLoc::new(4, -4);
+            let expected: Vec<_> = (0..=4).map(|i| VertHexLoc::new(i, -i)).collect();
+            assert_eq!(a.line_to(&b), expected);
+        }
+
+        #[test]
+        fn horiz_rotations() {
+            let center = HorizHexLoc::new(3, -1);
+            for dir in HorizHexDir::ALL {
+                let hex = center.get_neighbor(&dir);
+                assert_eq!(
+                    hex.rotate_right(&center),
+                    center.get_neighbor(&dir.right_60())
+                );
+                assert_eq!(
+                    hex.rotate_left(&center),
+                    center.get_neighbor(&dir.left_60())
+                );
+            }
+
+            for hex in center.spiral(3) {
+                let mut cur = hex;
+                for _ in 0..6 {
+                    cur = cur.rotate_right(&center);
+                    assert_eq!(cur.distance(&center), hex.distance(&center));
+                }
+                assert_eq!(cur, hex);
+                assert_eq!(hex.rotate_left(&center).rotate_right(&center), hex);
+            }
+        }
+
+        #[test]
+        fn vert_rotations() {
+            let center = VertHexLoc::new(-2, 5);
+            for dir in VertHexDir::ALL {
+                let hex = center.get_neighbor(&dir);
+                assert_eq!(
+                    hex.rotate_right(&center),
+                    center.get_neighbor(&dir.right_60())
+                );
+                assert_eq!(
+                    hex.rotate_left(&center),
+                    center.get_neighbor(&dir.left_60())
+                );
+            }
+        }
+
+        fn mirrored(d: Direction, north_south: bool) -> Direction {
+            match (d, north_south) {
+                (Direction::North, true) => Direction::South,
+                (Direction::South, true) => Direction::North,
+                (Direction::NorthEast, true) => Direction::SouthEast,
+                (Direction::SouthEast, true) => Direction::NorthEast,
+                (Direction::NorthWest, true) => Direction::SouthWest,
+                (Direction::SouthWest, true) => Direction::NorthWest,
+                (Direction::East, false) => Direction::West,
+                (Direction::West, false) => Direction::East,
+                (Direction::NorthEast, false) => Direction::NorthWest,
+                (Direction::NorthWest, false) => Direction::NorthEast,
+                (Direction::SouthEast, false) => Direction::SouthWest,
+                (Direction::SouthWest, false) => Direction::SouthEast,
+                (other, _) => other,
+            }
+        }
+
+        #[test]
+        fn reflections() {
+            let center = HorizHexLoc::new(1, 1);
+            for hex in center.spiral(3) {
+                for reflected in [
+                    hex.reflect_q(&center),
+                    hex.reflect_r(&center),
+                    hex.reflect_s(&center),
+                    hex.mirror_north_south(&center),
+                    hex.mirror_east_west(&center),
+                ] {
+                    assert_eq!(reflected.distance(&center), hex.distance(&center));
+                }
+                assert_eq!(hex.reflect_q(&center).reflect_q(&center), hex);
+                assert_eq!(hex.reflect_r(&center).reflect_r(&center), hex);
+                assert_eq!(hex.reflect_s(&center).reflect_s(&center), hex);
+            }
+
+            for dir in HorizHexDir::ALL {
+                let hex = center.get_neighbor(&dir);
+                for north_south in [true, false] {
+                    let expected: HorizHexDir = mirrored(dir.into(), north_south)
+                        .to_string()
+                        .parse()
+                        .unwrap();
+                    let res = if north_south {
+                        hex.mirror_north_south(&center)
+                    } else {
+                        hex.mirror_east_west(&center)
+                    };
+                    assert_eq!(res, center.get_neighbor(&expected));
+                }
+            }
+
+            let center = VertHexLoc::new(1, 1);
+            for dir in VertHexDir::ALL {
+                let hex = center.get_neighbor(&dir);
+                for north_south in [true, false] {
+                    let expected: VertHexDir = mirrored(dir.into(), north_south)
+                        .to_string()
+                        .parse()
+                        .unwrap();
+                    let res = if north_south {
+                        hex.mirror_north_south(&center)
+                    } else {
+                        hex.mirror_east_west(&center)
+                    };
+                    assert_eq!(res, center.get_neighbor(&expected));
+                }
+            }
+        }
+
+        #[test]
+        fn offsets() {
+            for parity in [OffsetParity::Odd, OffsetParity::Even] {
+                for hex in HorizHexLoc::new(1, -1).spiral(4) {
+                    let (row, col) = hex.to_offset(parity);
+                    assert_eq!(HorizHexLoc::from_offset(row, col, parity), hex);
+                }
+
+                for hex in VertHexLoc::new(-1, 2).spiral(4) {
+                    let (row, col) = hex.to_offset(parity);
+                    assert_eq!(VertHexLoc::from_offset(row, col, parity), hex);
+                }
+            }
+
+            // in odd-q, the south east neighbor of an even column is in the
+            // same row, while for an odd column it is one row down
+            let hex = HorizHexLoc::from_offset(2, 2, OffsetParity::Odd);
+            assert_eq!(
+                hex.get_neighbor(&HorizHexDir::SouthEast)
+                    .to_offset(OffsetParity::Odd),
+                (2, 3)
+            );
+            let hex = HorizHexLoc::from_offset(2, 3, OffsetParity::Odd);
+            assert_eq!(
+                hex.get_neighbor(&HorizHexDir::SouthEast)
+                    .to_offset(OffsetParity::Odd),
+                (3, 4)
+            );
+
+            // in even-r, the east neighbor is always in the same row
+            let hex = VertHexLoc::from_offset(-3, 5, OffsetParity::Even);
+            assert_eq!(
+                hex.get_neighbor(&VertHexDir::East)
+                    .to_offset(OffsetParity::Even),
+                (-3, 6)
+            );
+        }
+
+        #[test]
+        fn pixels() {
+            for hex in HorizHexLoc::default().spiral(5) {
+                let (x, y) = hex.to_pixel(4.0);
+                assert_eq!(HorizHexLoc::from_pixel(x, y, 4.0), hex);
+                assert_eq!(HorizHexLoc::from_pixel(x + 1.5, y - 1.5, 4.0), hex);
+            }
+
+            for hex in VertHexLoc::default().spiral(5) {
+                let (x, y) = hex.to_pixel(4.0);
+                assert_eq!(VertHexLoc::from_pixel(x, y, 4.0), hex);
+                assert_eq!(VertHexLoc::from_pixel(x - 1.5, y + 1.5, 4.0), hex);
+            }
+
+            // north is straight up for flat-topped hexes
+            let (x, y) = HorizHexLoc::default()
+                .get_neighbor(&HorizHexDir::North)
+                .to_pixel(1.0);
+            assert!(x.abs() < 1e-9 && (y + SQRT_3).abs() < 1e-9);
+
+            // east is straight right for pointy-topped hexes
+            let (x, y) = VertHexLoc::default()
+                .get_neighbor(&VertHexDir::East)
+                .to_pixel(1.0);
+            assert!((x - SQRT_3).abs() < 1e-9 && y.abs() < 1e-9);
+        }
+
+        #[test]
+        fn doubled() {
+            for hex in HorizHexLoc::new(2, -3).spiral(4) {
+                let (row, col) = hex.to_doubled();
+                assert_eq!((row + col) % 2, 0);
+                assert_eq!(HorizHexLoc::from_doubled(row, col), Some(hex));
+                assert_eq!(HorizHexLoc::from_doubled(row + 1, col), None);
+            }
+
+            for hex in VertHexLoc::new(-1, 4).spiral(4) {
+                let (row, col) = hex.to_doubled();
+                assert_eq!((row + col) % 2, 0);
+                assert_eq!(VertHexLoc::from_doubled(row, col), Some(hex));
+                assert_eq!(VertHexLoc::from_doubled(row, col - 1), None);
+            }
+
+            // north and south in doubled-height differ only by row
+            let hex = HorizHexLoc::new(3, 3);
+            let (row, col) = hex.to_doubled();
+            assert_eq!(
+                hex.get_neighbor(&HorizHexDir::North).to_doubled(),
+                (row - 2, col)
+            );
+            assert_eq!(
+                hex.get_neighbor(&HorizHexDir::NorthEast).to_doubled(),
+                (row - 1, col + 1)
+            );
+        }
+
+        #[test]
+        fn parsing_maps() {
+            // a flat-topped hexagon of radius one, in odd-q
+            let input = ".#.\n###\n###\n";
+            let map = HorizHexLoc::parse_offset(input, OffsetParity::Odd);
+            assert_eq!(map.len(), 9);
+            let center = HorizHexLoc::from_offset(1, 1, OffsetParity::Odd);
+            assert_eq!(map[&center], '#');
+            assert!(center.neighbors().all(|n| map[&n] == '#'));
+
+            // a hexagon drawn in doubled coordinates. Note that a
+            // string continuation would strip the significant leading spaces
+            let input = ["  #", " # #", "  #", " # #", "  #"].join("\n");
+            let map = HorizHexLoc::parse_doubled(&input).unwrap();
+            assert_eq!(map.len(), 7);
+            let center = HorizHexLoc::from_doubled(2, 2).unwrap();
+            assert!(center.neighbors().all(|n| map[&n] == '#'));
+
+            let input = ["  a b", " c d e", "  f g"].join("\n");
+            let map = VertHexLoc::parse_doubled(&input).unwrap();
+            let center = VertHexLoc::from_doubled(1, 3).unwrap();
+            assert_eq!(map[&center], 'd');
+            let neighbors: String = center.neighbors().map(|n| map[&n]).collect();
+            assert_eq!(neighbors, "ebacfg");
+
+            let map = VertHexLoc::parse_offset("ab\ncd", OffsetParity::Odd);
+            let c = VertHexLoc::from_offset(1, 0, OffsetParity::Odd);
+            assert_eq!(map[&c], 'c');
+            assert_eq!(map[&c.get_neighbor(&VertHexDir::NorthWest)], 'a');
+            assert_eq!(map[&c.get_neighbor(&VertHexDir::NorthEast)], 'b');
+
+            assert!(matches!(
+                VertHexLoc::parse_doubled("a b\nc d"),
+                Err(AocError::ParseLocationError(_))
+            ));
+        }
+
+        #[test]
+        fn walking() {
+            use crate::generic::directions::parse_hex_path;
+
+            for (path, end_dist, furthest) in [
+                ("ne,ne,ne", 3, 3),
+                ("ne,ne,sw,sw", 0, 2),
+                ("ne,ne,s,s", 2, 2),
+                ("se,sw,se,sw,sw", 3, 3),
+            ] {
+                let path: Vec<HorizHexDir> = parse_hex_path(path).unwrap();
+                let start = HorizHexLoc::new(4, -9);
+                let (end, max) = start.walk(&path);
+                assert_eq!(end.distance(&start), end_dist);
+                assert_eq!(max, furthest);
+            }
+
+            let path: Vec<VertHexDir> = parse_hex_path("nwwswee").unwrap();
+            let start = VertHexLoc::default();
+            assert_eq!(start.walk(&path), (start, 2));
+            assert_eq!(start.walk(&[]), (start, 0));
+        }
     }
 }
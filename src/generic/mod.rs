@@ -3,17 +3,27 @@
 //! Many of the types contained in this module are re-exported here for
 //! convenience.
 pub use self::bound::Bound2D;
+pub use self::bound::Bound3D;
 pub use self::grid::Grid;
+pub use self::hex_grid::HexGrid;
+pub use self::hex_grid::SparseHexGrid;
+pub use self::interval::Interval;
+pub use self::interval::IntervalSet;
 pub use self::location::HorizHexLoc;
 pub use self::location::Location;
 pub use self::location::VertHexLoc;
+pub use self::walker::Walker;
 
 // pub
 pub mod directions;
 pub mod grid;
+pub mod hex_grid;
+pub mod interval;
 pub mod location;
 pub mod pathing;
+pub mod polygon;
 pub mod prelude;
+pub mod walker;
 
 // "private"
 mod bound;
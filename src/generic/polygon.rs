@@ -0,0 +1,195 @@
+//! Area and lattice point calculations for simple polygons whose vertices lie
+//! on integer coordinates.
+//!
+//! Vertices are signed `(row, col)` pairs following the same convention as
+//! [Walker](super::walker::Walker), where North decreases the row. Vertex
+//! lists may be given in either winding order and may optionally repeat the
+//! first vertex at the end.
+//!
+//! # Examples
+//! ```
+//! use aoc_helpers::generic::directions::{parse_movements, Cardinal};
+//! use aoc_helpers::generic::polygon;
+//!
+//! let moves: Vec<(Cardinal, usize)> = parse_movements("R6 D5 L2 D2 L4 U7").unwrap();
+//! let vertices = polygon::vertices_from_moves((0, 0), &moves);
+//!
+//! assert_eq!(polygon::boundary_length(&vertices), 26);
+//! assert_eq!(polygon::interior_points(&vertices), 26);
+//! assert_eq!(polygon::enclosed_points(&vertices), 52);
+//! ```
+use num::integer::gcd;
+
+use super::directions::Cardinal;
+
+/// Returns twice the area of the polygon described by `vertices`, computed
+/// with the shoelace formula. Doubling keeps the result exact for polygons
+/// whose area is a half-integer.
+///
+/// Example
+/// ```
+/// use aoc_helpers::generic::polygon;
+///
+/// let triangle = vec![(0, 0), (0, 3), (1, 0)];
+/// assert_eq!(polygon::twice_area(&triangle), 3);
+/// ```
+pub fn twice_area(vertices: &[(i64, i64)]) -> i64 {
+    edges(vertices)
+        .map(|((r1, c1), (r2, c2))| c1 * r2 - c2 * r1)
+        .sum::<i64>()
+        .abs()
+}
+
+/// Returns the area of the polygon described by `vertices`.
+///
+/// Example
+/// ```
+/// use aoc_helpers::generic::polygon;
+///
+/// let square = vec![(0, 0), (0, 4), (4, 4), (4, 0)];
+/// assert_eq!(polygon::area(&square), 16.0);
+/// ```
+pub fn area(vertices: &[(i64, i64)]) -> f64 {
+    twice_area(vertices) as f64 / 2.0
+}
+
+/// Returns the number of lattice points on the boundary of the polygon, which
+/// for axis-aligned polygons is the same as the length of its perimeter.
+///
+/// Example
+/// ```
+/// use aoc_helpers::generic::polygon;
+///
+/// let diamond = vec![(0, 2), (2, 4), (4, 2), (2, 0)];
+/// assert_eq!(polygon::boundary_length(&diamond), 8);
+/// ```
+pub fn boundary_length(vertices: &[(i64, i64)]) -> i64 {
+    edges(vertices)
+        .map(|((r1, c1), (r2, c2))| gcd(r2 - r1, c2 - c1))
+        .sum()
+}
+
+/// Returns the number of lattice points strictly inside the polygon, using
+/// Pick's theorem.
+///
+/// Example
+/// ```
+/// use aoc_helpers::generic::polygon;
+///
+/// let square = vec![(0, 0), (0, 4), (4, 4), (4, 0)];
+/// assert_eq!(polygon::interior_points(&square), 9);
+/// ```
+pub fn interior_points(vertices: &[(i64, i64)]) -> i64 {
+    // Pick's theorem: A = i + b/2 - 1, so 2i = 2A - b + 2
+    (twice_area(vertices) - boundary_length(vertices) + 2) / 2
+}
+
+/// Returns the number of lattice points either inside or on the boundary of
+/// the polygon.
+pub fn enclosed_points(vertices: &[(i64, i64)]) -> i64 {
+    interior_points(vertices) + boundary_length(vertices)
+}
+
+/// Builds the list of vertices visited by starting at `start` and following
+/// each `(Cardinal, length)` move in turn. The final position is only
+/// included if it differs from `start`, so a closed path yields each vertex
+/// exactly once. Pairs well with
+/// [parse_movements](super::directions::parse_movements).
+///
+/// Example
+/// ```
+/// use aoc_helpers::generic::directions::Cardinal;
+/// use aoc_helpers::generic::polygon;
+///
+/// let moves = vec![(Cardinal::East, 2), (Cardinal::South, 3), (Cardinal::West, 2), (Cardinal::North, 3)];
+///
+/// assert_eq!(
+///     polygon::vertices_from_moves((0, 0), &moves),
+///     vec![(0, 0), (0, 2), (3, 2), (3, 0)],
+/// );
+/// ```
+pub fn vertices_from_moves(start: (i64, i64), moves: &[(Cardinal, usize)]) -> Vec<(i64, i64)> {
+    let mut vertices = Vec::with_capacity(moves.len() + 1);
+    vertices.push(start);
+
+    let mut cur = start;
+    for (dir, len) in moves {
+        let (dr, dc) = dir.delta();
+        let len = *len as i64;
+        cur = (cur.0 + dr * len, cur.1 + dc * len);
+        vertices.push(cur);
+    }
+
+    if vertices.len() > 1 && vertices.last() == Some(&start) {
+        vertices.pop();
+    }
+
+    vertices
+}
+
+/// Yields each edge of the polygon, including the one closing it.
+fn edges(vertices: &[(i64, i64)]) -> impl Iterator<Item = ((i64, i64), (i64, i64))> + '_ {
+    vertices
+        .iter()
+        .copied()
+        .zip(vertices.iter().copied().cycle().skip(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn areas() {
+        assert_eq!(twice_area(&[]), 0);
+        assert_eq!(twice_area(&[(1, 1)]), 0);
+
+        let clockwise = vec![(0, 0), (0, 5), (3, 5), (3, 0)];
+        let mut counter = clockwise.clone();
+        counter.reverse();
+        assert_eq!(area(&clockwise), 15.0);
+        assert_eq!(area(&counter), 15.0);
+
+        // repeating the first vertex makes no difference
+        let mut closed = clockwise.clone();
+        closed.push((0, 0));
+        assert_eq!(area(&closed), 15.0);
+        assert_eq!(boundary_length(&closed), 16);
+
+        let triangle = vec![(0, 0), (1, 0), (0, 1)];
+        assert_eq!(area(&triangle), 0.5);
+    }
+
+    #[test]
+    fn picks() {
+        // an L shape made of a 4x2 and a 2x2 block of cells
+        let moves = vec![
+            (Cardinal::East, 4),
+            (Cardinal::South, 2),
+            (Cardinal::West, 2),
+            (Cardinal::South, 2),
+            (Cardinal::West, 2),
+            (Cardinal::North, 4),
+        ];
+        let vertices = vertices_from_moves((0, 0), &moves);
+        assert_eq!(vertices.len(), 6);
+        assert_eq!(twice_area(&vertices), 24);
+        assert_eq!(boundary_length(&vertices), 16);
+        assert_eq!(interior_points(&vertices), 5);
+        assert_eq!(enclosed_points(&vertices), 21);
+
+        let diamond = vec![(0, 2), (2, 4), (4, 2), (2, 0)];
+        assert_eq!(interior_points(&diamond), 5);
+        assert_eq!(enclosed_points(&diamond), 13);
+    }
+
+    #[test]
+    fn open_paths() {
+        let moves = vec![(Cardinal::North, 3), (Cardinal::West, 1)];
+        assert_eq!(
+            vertices_from_moves((5, 5), &moves),
+            vec![(5, 5), (2, 5), (2, 4)]
+        );
+        assert_eq!(vertices_from_moves((5, 5), &[]), vec![(5, 5)]);
+    }
+}
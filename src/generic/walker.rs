@@ -0,0 +1,392 @@
+//! A [Walker] tracks a position and a heading, and is useful for simulating
+//! movement through a grid or across an unbounded plane.
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::hash::Hash;
+
+use super::directions::{Cardinal, Direction, Turn};
+use super::grid::GridLike;
+use super::Location;
+
+/// Things that can be used as the position of a [Walker].
+///
+/// Implementations exist for [Location] and for signed `(row, col)` pairs of
+/// [i64], both following the convention that North decreases the row and
+/// East increases the column.
+pub trait Position: Copy + Eq + Hash {
+    /// Returns the position `n` steps away in the given direction, or `None`
+    /// if that position is not representable.
+    fn step_n(&self, dir: Direction, n: usize) -> Option<Self>;
+}
+
+impl Position for Location {
+    fn step_n(&self, dir: Direction, n: usize) -> Option<Self> {
+        Location::step_n(self, dir, n)
+    }
+}
+
+impl Position for (i64, i64) {
+    fn step_n(&self, dir: Direction, n: usize) -> Option<Self> {
+        // i128 holds any i64 plus a delta times any usize without overflowing
+        let offset = |v: i64, d: i64| i64::try_from(v as i128 + d as i128 * n as i128).ok();
+        let (dr, dc) = dir.delta();
+        Some((offset(self.0, dr)?, offset(self.1, dc)?))
+    }
+}
+
+/// Things that can be used as the heading of a [Walker].
+pub trait Heading: Copy + Eq + Hash + Into<Direction> {
+    /// Returns the heading resulting from applying the given [Turn].
+    fn turn(&self, turn: Turn) -> Self;
+}
+
+impl Heading for Cardinal {
+    fn turn(&self, turn: Turn) -> Self {
+        Cardinal::turn(self, turn)
+    }
+}
+
+impl Heading for Direction {
+    fn turn(&self, turn: Turn) -> Self {
+        Direction::turn(self, turn)
+    }
+}
+
+/// The outcome of attempting a single step with [`Walker::step_in`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Step {
+    /// The walker moved forward.
+    Moved,
+    /// The next position was a wall, so the walker did not move.
+    Blocked,
+    /// The next position was outside of the grid, so the walker did not move.
+    Exited,
+    /// The walker moved forward into a `(position, heading)` state it has
+    /// already been in.
+    Looped,
+}
+
+/// A `Walker` combines a [Position] and a [Heading], remembering every
+/// position it has visited and every `(position, heading)` state it has been
+/// in so that loops can be detected.
+///
+/// # Examples
+/// ```
+/// use aoc_helpers::generic::walker::Walker;
+/// use aoc_helpers::generic::directions::{Cardinal, Turn};
+///
+/// let mut walker = Walker::new((0_i64, 0_i64), Cardinal::North);
+/// walker.forward_n(2);
+/// walker.turn(Turn::Right);
+/// walker.forward_n(3);
+///
+/// assert_eq!(walker.position, (-2, 3));
+/// assert_eq!(walker.heading, Cardinal::East);
+/// assert_eq!(walker.visited().len(), 6);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Walker<P, H>
+where
+    P: Position,
+    H: Heading,
+{
+    pub position: P,
+    pub heading: H,
+    visited: HashSet<P>,
+    states: HashSet<(P, H)>,
+    looped: bool,
+}
+
+impl<P, H> Walker<P, H>
+where
+    P: Position,
+    H: Heading,
+{
+    pub fn new(position: P, heading: H) -> Self {
+        let mut walker = Self {
+            position,
+            heading,
+            visited: HashSet::new(),
+            states: HashSet::new(),
+            looped: false,
+        };
+        walker.record();
+        walker
+    }
+
+    /// The set of every position this walker has occupied, including the
+    /// starting position.
+    pub fn visited(&self) -> &HashSet<P> {
+        &self.visited
+    }
+
+    /// Whether or not this walker has ever re-entered a `(position, heading)`
+    /// state it had already been in.
+    pub fn has_looped(&self) -> bool {
+        self.looped
+    }
+
+    /// Returns the position directly in front of this walker, if any.
+    pub fn ahead(&self) -> Option<P> {
+        self.position.step_n(self.heading.into(), 1)
+    }
+
+    /// Applies the given [Turn] to this walker's heading.
+    pub fn turn(&mut self, turn: Turn) {
+        self.face(self.heading.turn(turn));
+    }
+
+    pub fn turn_left(&mut self) {
+        self.turn(Turn::Left);
+    }
+
+    pub fn turn_right(&mut self) {
+        self.turn(Turn::Right);
+    }
+
+    /// Sets this walker's heading to the given value. Facing the current
+    /// heading leaves the walker in the same state, so it is not recorded.
+    pub fn face(&mut self, heading: H) {
+        if heading != self.heading {
+            self.heading = heading;
+            self.record();
+        }
+    }
+
+    /// Moves one step forward, returning `false` if the next position could
+    /// not be represented (such as a negative row in a [Location]).
+    pub fn forward(&mut self) -> bool {
+        match self.ahead() {
+            Some(next) => {
+                self.position = next;
+                self.record();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves up to `n` steps forward, returning the number of steps actually
+    /// taken.
+    pub fn forward_n(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.forward()).count()
+    }
+
+    /// Attempts to move one step forward within `grid`, treating any cell for
+    /// which `is_wall` returns `true` as impassable.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc_helpers::generic::{Grid, Location};
+    /// use aoc_helpers::generic::walker::{Step, Walker};
+    /// use aoc_helpers::generic::directions::Cardinal;
+    ///
+    /// let grid = Grid::new(vec![
+    ///     vec!['.', '#'],
+    ///     vec!['.', '.'],
+    /// ]);
+    /// let mut walker = Walker::new(Location::new(1, 1), Cardinal::North);
+    ///
+    /// assert_eq!(walker.step_in(&grid, |c| *c == '#'), Step::Blocked);
+    /// walker.turn_left();
+    /// assert_eq!(walker.step_in(&grid, |c| *c == '#'), Step::Moved);
+    /// assert_eq!(walker.step_in(&grid, |c| *c == '#'), Step::Exited);
+    /// ```
+    pub fn step_in<G, F>(&mut self, grid: &G, is_wall: F) -> Step
+    where
+        G: GridLike<Location = P>,
+        F: Fn(&G::Item) -> bool,
+    {
+        let next = match self.ahead() {
+            Some(next) => next,
+            None => return Step::Exited,
+        };
+
+        match grid.get(&next) {
+            None => Step::Exited,
+            Some(v) if is_wall(v) => Step::Blocked,
+            Some(_) => {
+                self.position = next;
+                if self.record() {
+                    Step::Moved
+                } else {
+                    Step::Looped
+                }
+            }
+        }
+    }
+
+    /// Follows a sequence of relative instructions, turning before moving
+    /// forward the given number of steps. Pairs well with
+    /// [parse_movements](super::directions::parse_movements).
+    pub fn follow_turns(&mut self, instructions: &[(Turn, usize)]) {
+        for (turn, steps) in instructions {
+            self.turn(*turn);
+            self.forward_n(*steps);
+        }
+    }
+
+    /// Follows a sequence of absolute instructions, facing the given heading
+    /// before moving forward the given number of steps.
+    pub fn follow_headings(&mut self, instructions: &[(H, usize)]) {
+        for (heading, steps) in instructions {
+            self.face(*heading);
+            self.forward_n(*steps);
+        }
+    }
+
+    /// Records the current state, returning `false` if it had been seen before.
+    fn record(&mut self) -> bool {
+        self.visited.insert(self.position);
+        let new = self.states.insert((self.position, self.heading));
+        self.looped |= !new;
+        new
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generic::directions::parse_movements;
+    use crate::generic::Grid;
+
+    #[test]
+    fn signed_walking() {
+        let mut walker = Walker::new((0_i64, 0_i64), Cardinal::North);
+        assert_eq!(walker.forward_n(3), 3);
+        assert_eq!(walker.position, (-3, 0));
+
+        walker.turn(Turn::Back);
+        assert_eq!(walker.heading, Cardinal::South);
+        walker.forward_n(5);
+        assert_eq!(walker.position, (2, 0));
+        assert_eq!(walker.visited().len(), 6);
+        assert!(!walker.has_looped());
+    }
+
+    #[test]
+    fn signed_limits() {
+        let start = (i64::MAX - 1, i64::MIN);
+        assert_eq!(
+            start.step_n(Direction::South, 1),
+            Some((i64::MAX, i64::MIN))
+        );
+        assert_eq!(start.step_n(Direction::South, 2), None);
+        assert_eq!(start.step_n(Direction::West, 1), None);
+        assert_eq!(
+            start.step_n(Direction::East, usize::MAX),
+            Some((i64::MAX - 1, i64::MAX))
+        );
+        assert_eq!((0, 1).step_n(Direction::East, usize::MAX), None);
+        assert_eq!(
+            (i64::MIN, 0).step_n(Direction::South, 1 << 63),
+            Some((0, 0))
+        );
+
+        let mut walker = Walker::new(start, Cardinal::South);
+        assert_eq!(walker.forward_n(usize::MAX), 1);
+        assert_eq!(walker.position, (i64::MAX, i64::MIN));
+    }
+
+    #[test]
+    fn location_walking() {
+        let mut walker = Walker::new(Location::new(1, 1), Direction::NorthWest);
+        assert_eq!(walker.forward_n(3), 1);
+        assert_eq!(walker.position, Location::new(0, 0));
+
+        walker.turn_right();
+        assert_eq!(walker.heading, Direction::NorthEast);
+        assert!(!walker.forward());
+        assert_eq!(walker.position, Location::new(0, 0));
+    }
+
+    #[test]
+    fn following() {
+        let instructions = vec![(Turn::Right, 2), (Turn::Right, 2), (Turn::Right, 2)];
+        let mut walker = Walker::new((0_i64, 0_i64), Cardinal::North);
+        walker.follow_turns(&instructions);
+        assert_eq!(walker.position, (2, 0));
+        assert_eq!(walker.heading, Cardinal::West);
+
+        let instructions = vec![(Cardinal::East, 2), (Cardinal::North, 1)];
+        let mut walker = Walker::new((0_i64, 0_i64), Cardinal::North);
+        walker.follow_headings(&instructions);
+        assert_eq!(walker.position, (-1, 2));
+        assert_eq!(walker.heading, Cardinal::North);
+    }
+
+    #[test]
+    fn loop_detection() {
+        // revisiting a position with a different heading is not a loop
+        let mut walker = Walker::new((0_i64, 0_i64), Cardinal::East);
+        walker.forward();
+        walker.turn(Turn::Back);
+        walker.forward();
+        assert_eq!(walker.position, (0, 0));
+        assert!(!walker.has_looped());
+
+        // but returning with the same heading is
+        walker.turn(Turn::Back);
+        assert!(walker.has_looped());
+    }
+
+    #[test]
+    fn unchanged_headings() {
+        let mut walker = Walker::new((0_i64, 0_i64), Cardinal::East);
+        walker.turn(Turn::Straight);
+        walker.face(Cardinal::East);
+        assert!(!walker.has_looped());
+
+        walker.follow_headings(&[(Cardinal::East, 2), (Cardinal::East, 1)]);
+        assert_eq!(walker.position, (0, 3));
+        assert!(!walker.has_looped());
+
+        let instructions: Vec<(Turn, usize)> = parse_movements("F2 F1 R1").unwrap();
+        let mut walker = Walker::new((0_i64, 0_i64), Cardinal::North);
+        walker.follow_turns(&instructions);
+        assert_eq!(walker.position, (-3, 1));
+        assert!(!walker.has_looped());
+
+        let grid = Grid::new(vec![vec!['.', '.', '.']]);
+        let mut walker = Walker::new(Location::new(0, 0), Cardinal::East);
+        walker.turn(Turn::Straight);
+        assert_eq!(walker.step_in(&grid, |_| false), Step::Moved);
+        assert!(!walker.has_looped());
+    }
+
+    #[test]
+    fn grid_walking() {
+        let grid = Grid::new(vec![
+            ".##..".chars().collect(),
+            "....#".chars().collect(),
+            ".....".chars().collect(),
+            "#....".chars().collect(),
+            "...#.".chars().collect(),
+        ]);
+        let is_wall = |c: &char| *c == '#';
+
+        let mut walker = Walker::new(Location::new(2, 2), Cardinal::North);
+        let mut steps = Vec::new();
+        for _ in 0..20 {
+            let step = walker.step_in(&grid, is_wall);
+            steps.push(step);
+            match step {
+                Step::Blocked => walker.turn_right(),
+                Step::Moved => {}
+                Step::Exited | Step::Looped => break,
+            }
+        }
+
+        assert_eq!(steps.last(), Some(&Step::Looped));
+        assert!(walker.has_looped());
+        assert!(steps.contains(&Step::Blocked));
+
+        let mut walker = Walker::new(Location::new(0, 0), Cardinal::West);
+        assert_eq!(walker.step_in(&grid, is_wall), Step::Exited);
+        walker.face(Cardinal::South);
+        assert_eq!(walker.step_in(&grid, is_wall), Step::Moved);
+        assert_eq!(walker.step_in(&grid, is_wall), Step::Moved);
+        assert_eq!(walker.step_in(&grid, is_wall), Step::Blocked);
+        assert_eq!(walker.position, Location::new(2, 0));
+    }
+}
@@ -0,0 +1,474 @@
+//! Configurable discovery of puzzle inputs on disk.
+//!
+//! An [InputResolver] searches for the input for a day beneath a root
+//! directory using a list of layouts, which are `/`-separated path templates
+//! relative to the root. Layouts may contain the following placeholders:
+//!
+//! - `{year}`: the configured year. Layouts using it are skipped if no year
+//!   is configured.
+//! - `{day}`: the day, zero-padded to three digits, like `003`.
+//! - `{day:N}`: the day, zero-padded to `N` digits.
+//! - `{name}`: the name of the input, like `input` or `example`.
+//!
+//! A path component ending in `*` matches any entry starting with the rest of
+//! the component, so `{day}*` matches a directory like `003_toboggan-trajectory`.
+//!
+//! By default, inputs are found at `examples/{year}/{day}*/{name}` and
+//! `examples/{day}*/{name}`. The defaults may be replaced by a JSON config file
+//! given by the `AOC_CONFIG` ENV var, like
+//!
+//! ```json
+//! { "root": "../inputs", "year": 2022, "layouts": ["{year}/day{day:2}/{name}.txt"] }
+//! ```
+//!
+//! and individual settings may be overridden by the `AOC_INPUT_ROOT`,
+//! `AOC_YEAR` and `AOC_INPUT_NAME` ENV vars. See [InputResolver::from_env].
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{AocError, Result};
+use crate::util::load_raw;
+
+/// Locates inputs beneath a root directory according to a set of layouts.
+///
+/// # Usage
+///
+/// ```no_run
+/// use aoc_helpers::input::InputResolver;
+///
+/// let resolver = InputResolver::new()
+///     .with_root("/home/me/aoc-inputs/alice")
+///     .with_year(2022)
+///     .with_layouts(vec!["{year}/{day:2}/{name}.txt"]);
+///
+/// // /home/me/aoc-inputs/alice/2022/03/input.txt
+/// let input = resolver.load_input(3).expect("could not load input");
+/// // /home/me/aoc-inputs/alice/2022/03/example.txt
+/// let example = resolver.load(3, "example").expect("could not load example");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct InputResolver {
+    /// The directory layouts are relative to.
+    pub root: PathBuf,
+    /// The year substituted for `{year}`, if any.
+    pub year: Option<u32>,
+    /// The name of the input loaded by [load_input](InputResolver::load_input).
+    pub input_name: String,
+    /// The layouts to search, in order.
+    pub layouts: Vec<String>,
+}
+
+impl Default for InputResolver {
+    fn default() -> Self {
+        Self {
+            root: PathBuf::from("examples"),
+            year: None,
+            input_name: "input".into(),
+            layouts: vec!["{year}/{day}*/{name}".into(), "{day}*/{name}".into()],
+        }
+    }
+}
+
+impl InputResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a resolver from the config file given by `AOC_CONFIG`, if set,
+    /// then applies any of the `AOC_INPUT_ROOT`, `AOC_YEAR` and
+    /// `AOC_INPUT_NAME` ENV vars which are set.
+    pub fn from_env() -> Result<Self> {
+        let mut resolver = match env::var("AOC_CONFIG") {
+            Ok(path) => Self::from_config(path)?,
+            Err(_) => Self::default(),
+        };
+
+        if let Ok(root) = env::var("AOC_INPUT_ROOT") {
+            resolver.root = PathBuf::from(root);
+        }
+
+        if let Ok(year) = env::var("AOC_YEAR") {
+            resolver.year = Some(year.trim().parse()?);
+        }
+
+        if let Ok(name) = env::var("AOC_INPUT_NAME") {
+            resolver.input_name = name;
+        }
+
+        Ok(resolver)
+    }
+
+    /// Reads a resolver from a JSON config file. Omitted settings keep their
+    /// defaults.
+    pub fn from_config<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        Self::parse_config(&contents)
+    }
+
+    /// Parses a resolver from the contents of a JSON config file.
+    ///
+    /// ```
+    /// use aoc_helpers::input::InputResolver;
+    ///
+    /// let resolver = InputResolver::parse_config(r#"{"root": "inputs", "year": 2021}"#).unwrap();
+    /// assert_eq!(resolver, InputResolver::new().with_root("inputs").with_year(2021));
+    /// ```
+    pub fn parse_config(contents: &str) -> Result<Self> {
+        Ok(serde_json::from_str(contents)?)
+    }
+
+    pub fn with_root<P: Into<PathBuf>>(mut self, root: P) -> Self {
+        self.root = root.into();
+        self
+    }
+
+    pub fn with_year(mut self, year: u32) -> Self {
+        self.year = Some(year);
+        self
+    }
+
+    pub fn with_input_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.input_name = name.into();
+        self
+    }
+
+    /// Replaces the layouts to search.
+    pub fn with_layouts<S: Into<String>>(mut self, layouts: Vec<S>) -> Self {
+        self.layouts = layouts.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Finds the path of the input with the given name for the given day,
+    /// trying each layout in order. If no file is found, the error lists every
+    /// path that was tried.
+    ///
+    /// ```
+    /// use aoc_helpers::input::InputResolver;
+    ///
+    /// let err = InputResolver::new()
+    ///     .with_root("missing")
+    ///     .with_year(2020)
+    ///     .resolve(7, "input")
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "specified input was missing: 007: 'input' (tried missing/2020/007*/input, missing/007*/input)"
+    /// );
+    /// ```
+    pub fn resolve(&self, day: usize, name: &str) -> Result<PathBuf> {
+        let mut tried = Vec::new();
+        for layout in &self.layouts {
+            let components = match self.expand(layout, day, name) {
+                Some(components) => components,
+                None => continue,
+            };
+
+            let matches = self.walk(&components);
+            if let Some(path) = matches.iter().find(|p| p.is_file()) {
+                return Ok(path.clone());
+            }
+
+            if matches.is_empty() {
+                tried.push(
+                    components
+                        .iter()
+                        .fold(self.root.clone(), |path, c| path.join(c)),
+                );
+            } else {
+                tried.extend(matches);
+            }
+        }
+
+        let tried: Vec<String> = tried.iter().map(|p| p.display().to_string()).collect();
+        Err(AocError::InputMissing(format!(
+            "{:03}: '{}' (tried {})",
+            day,
+            name,
+            if tried.is_empty() {
+                "no layouts".to_string()
+            } else {
+                tried.join(", ")
+            }
+        )))
+    }
+
+    /// Reads the input with the given name for the given day.
+    pub fn load(&self, day: usize, name: &str) -> Result<String> {
+        let path = self.resolve(day, name)?;
+        load_raw(&path.to_string_lossy())
+    }
+
+    /// Reads the input for the given day, using the configured
+    /// [input_name](InputResolver::input_name).
+    pub fn load_input(&self, day: usize) -> Result<String> {
+        self.load(day, &self.input_name)
+    }
+
+    /// Reads the input with the given name for a day given as a string, like
+    /// `"003"`. A numeric day is resolved through the layouts first. If that
+    /// fails, or the day is not a number, the input is read from the first
+    /// directory directly beneath the root whose name starts with `day`, so
+    /// that `"3"` matches `3_foo` and `"3_custom"` matches itself.
+    pub fn load_day(&self, day: &str, name: &str) -> Result<String> {
+        let resolved = day.parse().map(|day| self.resolve(day, name));
+        if let Ok(Ok(ref path)) = resolved {
+            return load_raw(&path.to_string_lossy());
+        }
+
+        let components = [format!("{}*", day), name.to_string()];
+        match self.walk(&components).iter().find(|p| p.is_file()) {
+            Some(path) => load_raw(&path.to_string_lossy()),
+            None => match resolved {
+                Ok(Err(e)) => Err(e),
+                _ => Err(AocError::InputMissing(format!("{}: '{}'", day, name))),
+            },
+        }
+    }
+
+    /// Lists the names of every input available for the given day, across
+    /// all layouts, in sorted order.
+    pub fn names(&self, day: usize) -> Vec<String> {
+        let mut names = BTreeSet::new();
+        for layout in &self.layouts {
+            let mut components = match self.expand(layout, day, "") {
+                Some(components) => components,
+                None => continue,
+            };
+
+            // the name is surrounded by whatever else is in the final component
+            let last = layout.rsplit('/').next().unwrap_or_default();
+            let idx = match last.find("{name}") {
+                Some(idx) => idx,
+                None => continue,
+            };
+            let prefix = self.substitute(&last[..idx], day, "");
+            let suffix = self.substitute(&last[idx + "{name}".len()..], day, "");
+            components.pop();
+
+            for dir in self.walk(&components) {
+                for entry in fs::read_dir(dir)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|e| e.ok())
+                {
+                    let file = entry.file_name().to_string_lossy().to_string();
+                    if !entry.path().is_file() || file.len() <= prefix.len() + suffix.len() {
+                        continue;
+                    }
+
+                    if let Some(name) = file
+                        .strip_prefix(prefix.as_str())
+                        .and_then(|f| f.strip_suffix(suffix.as_str()))
+                    {
+                        names.insert(name.to_string());
+                    }
+                }
+            }
+        }
+
+        names.into_iter().collect()
+    }
+
+    /// Substitutes the placeholders in each component of the given layout, or
+    /// returns `None` if the layout needs a year and none is configured.
+    fn expand(&self, layout: &str, day: usize, name: &str) -> Option<Vec<String>> {
+        if layout.contains("{year}") && self.year.is_none() {
+            return None;
+        }
+
+        Some(
+            layout
+                .split('/')
+                .filter(|c| !c.is_empty())
+                .map(|c| self.substitute(c, day, name))
+                .collect(),
+        )
+    }
+
+    fn substitute(&self, component: &str, day: usize, name: &str) -> String {
+        let mut out = component.replace("{name}", name);
+        if let Some(year) = self.year {
+            out = out.replace("{year}", &year.to_string());
+        }
+        out = out.replace("{day}", &format!("{:03}", day));
+
+        while let Some(start) = out.find("{day:") {
+            let end = match out[start..].find('}') {
+                Some(end) => start + end,
+                None => break,
+            };
+            let width: usize = match out[start + 5..end].parse() {
+                Ok(width) => width,
+                Err(_) => break,
+            };
+            out.replace_range(start..=end, &format!("{:0width$}", day, width = width));
+        }
+
+        out
+    }
+
+    /// Returns the paths matching the given components beneath the root. A
+    /// component ending in `*` matches entries by prefix, in sorted order.
+    fn walk(&self, components: &[String]) -> Vec<PathBuf> {
+        let mut paths = vec![self.root.clone()];
+        for component in components {
+            paths = match component.strip_suffix('*') {
+                Some(prefix) => paths
+                    .iter()
+                    .flat_map(|dir| {
+                        let mut entries: Vec<PathBuf> = fs::read_dir(dir)
+                            .into_iter()
+                            .flatten()
+                            .filter_map(|e| e.ok())
+                            .filter(|e| e.file_name().to_string_lossy().starts_with(prefix))
+                            .map(|e| e.path())
+                            .collect();
+                        entries.sort();
+                        entries
+                    })
+                    .collect(),
+                None => paths.iter().map(|p| p.join(component)).collect(),
+            };
+        }
+
+        paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a fresh directory containing the given files.
+    fn tree(test: &str, files: &[&str]) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc_helpers_{}_{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, file).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn substitution() {
+        let resolver = InputResolver::new().with_year(2022);
+        assert_eq!(
+            resolver.substitute("{year}-{day}-{day:2}-{day:1}-{name}.txt", 5, "input"),
+            "2022-005-05-5-input.txt"
+        );
+        assert_eq!(
+            InputResolver::new().expand("{year}/{day}*/{name}", 5, "input"),
+            None
+        );
+    }
+
+    #[test]
+    fn default_layouts() {
+        let root = tree(
+            "default_layouts",
+            &[
+                "003_toboggan/input",
+                "003_toboggan/example",
+                "2021/003_other/input",
+            ],
+        );
+        let resolver = InputResolver::new().with_root(&root);
+
+        assert_eq!(
+            resolver.load_input(3).unwrap(),
+            "003_toboggan/input".to_string()
+        );
+        assert_eq!(
+            resolver.clone().with_year(2021).load_input(3).unwrap(),
+            "2021/003_other/input"
+        );
+        assert_eq!(
+            resolver.clone().with_year(2021).load(3, "example").unwrap(),
+            "003_toboggan/example"
+        );
+        assert_eq!(resolver.names(3), vec!["example", "input"]);
+
+        match resolver.resolve(3, "missing") {
+            Err(AocError::InputMissing(msg)) => {
+                let tried = root.join("003_toboggan").join("missing");
+                assert!(msg.starts_with("003: 'missing' (tried "));
+                assert!(msg.contains(&tried.display().to_string()));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn custom_layouts() {
+        let root = tree(
+            "custom_layouts",
+            &["alice/2022/day07/input.txt", "alice/2022/day07/small.txt"],
+        );
+        let resolver = InputResolver::parse_config(&format!(
+            r#"{{"root": {:?}, "year": 2022, "layouts": ["{{year}}/day{{day:2}}/{{name}}.txt"]}}"#,
+            root.join("alice").display().to_string()
+        ))
+        .unwrap();
+
+        assert_eq!(resolver.input_name, "input");
+        assert_eq!(
+            resolver.load_input(7).unwrap(),
+            "alice/2022/day07/input.txt"
+        );
+        assert_eq!(
+            resolver
+                .clone()
+                .with_input_name("small")
+                .load_input(7)
+                .unwrap(),
+            "alice/2022/day07/small.txt"
+        );
+        assert_eq!(resolver.names(7), vec!["input", "small"]);
+        assert!(resolver.load_input(8).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn day_prefixes() {
+        let root = tree(
+            "day_prefixes",
+            &["3_foo/input", "3_custom/input", "003_bar/input", "x/input"],
+        );
+        let resolver = InputResolver::new().with_root(&root);
+
+        assert_eq!(resolver.load_day("003", "input").unwrap(), "003_bar/input");
+        assert_eq!(resolver.load_day("3", "input").unwrap(), "003_bar/input");
+        assert_eq!(
+            resolver.load_day("3_custom", "input").unwrap(),
+            "3_custom/input"
+        );
+        assert_eq!(resolver.load_day("x", "input").unwrap(), "x/input");
+
+        fs::remove_dir_all(root.join("003_bar")).unwrap();
+        assert_eq!(resolver.load_day("3", "input").unwrap(), "3_custom/input");
+        assert_eq!(resolver.load_day("3_f", "input").unwrap(), "3_foo/input");
+        fs::remove_dir_all(root.join("3_custom")).unwrap();
+        assert_eq!(resolver.load_day("3", "input").unwrap(), "3_foo/input");
+
+        assert_eq!(
+            resolver.load_day("y", "input").unwrap_err().to_string(),
+            "specified input was missing: y: 'input'"
+        );
+        assert!(resolver
+            .load_day("4", "input")
+            .unwrap_err()
+            .to_string()
+            .contains("004: 'input' (tried "));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
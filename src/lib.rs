@@ -1,12 +1,22 @@
 //! This crate provides common functionality and structures to aid in solving
 //! Advent of Code problems. This is very much geared towards the way I solve
 //! these problems, so there are no guarantees of compatibility.
+pub use solution::SharedSolver;
+#[allow(deprecated)]
 pub use solution::Solution;
+#[allow(deprecated)]
 pub use solution::Solver;
+pub use solution::TrySolver;
+#[allow(deprecated)]
 pub use util::load_input;
+pub use util::load_raw_input;
+#[allow(deprecated)]
 pub use util::parse_input;
 
 pub mod error;
 pub mod generic;
+pub mod input;
+pub mod output;
+pub mod runner;
 pub mod solution;
 pub mod util;
@@ -0,0 +1,477 @@
+//! Explicit output formats for a single [Solution] or for the solutions of many
+//! days at once.
+//!
+//! ```
+//! use aoc_helpers::output::{OutputFormat, Report};
+//! use aoc_helpers::Solution;
+//!
+//! let reports = vec![
+//!     Report::new(1, "001 foo", Ok(Solution::new(12, 34))),
+//!     Report::new(2, "002 bar", Err("it broke".to_string())),
+//! ];
+//!
+//! assert_eq!(
+//!     OutputFormat::Csv.format_reports(&reports).unwrap(),
+//!     "day,label,part_one,part_two,error\n1,001 foo,12,34,\n2,002 bar,,,it broke"
+//! );
+//! assert_eq!(
+//!     OutputFormat::Markdown.format_reports(&reports).unwrap(),
+//!     [
+//!         "| day | label | part 1 | part 2 | error |",
+//!         "| --- | --- | --- | --- | --- |",
+//!         "| 1 | 001 foo | 12 | 34 |  |",
+//!         "| 2 | 002 bar |  |  | it broke |",
+//!     ]
+//!     .join("\n")
+//! );
+//! ```
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::env;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::error::{AocError, Result};
+#[allow(deprecated)]
+use crate::solution::{nanos, Solution, Timings};
+
+/// The supported output formats.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub enum OutputFormat {
+    /// `part 1: ...` and `part 2: ...` lines, with each day preceded by its
+    /// label and separated by a blank line.
+    #[default]
+    Plain,
+    /// A single JSON object, or an array of objects for many days.
+    Json,
+    /// One JSON object per line.
+    JsonLines,
+    /// Comma-separated values with a header row.
+    Csv,
+    /// A Markdown table.
+    Markdown,
+}
+
+impl FromStr for OutputFormat {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "plain" | "text" => Ok(OutputFormat::Plain),
+            "json" => Ok(OutputFormat::Json),
+            "jsonl" | "json-lines" | "ndjson" => Ok(OutputFormat::JsonLines),
+            "csv" => Ok(OutputFormat::Csv),
+            "markdown" | "md" => Ok(OutputFormat::Markdown),
+            _ => Err(AocError::UsageError(format!(
+                "unknown output format: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// The outcome of solving a single day, for formatting alongside others.
+#[derive(Debug, PartialEq)]
+#[allow(deprecated)]
+pub struct Report<T, G>
+where
+    T: Display + Serialize + PartialEq,
+    G: Display + Serialize + PartialEq,
+{
+    pub day: usize,
+    pub label: String,
+    /// The solution, or a description of why it could not be produced.
+    pub outcome: std::result::Result<Solution<T, G>, String>,
+}
+
+impl<T, G> Report<T, G>
+where
+    T: Display + Serialize + PartialEq,
+    G: Display + Serialize + PartialEq,
+{
+    #[allow(deprecated)]
+    pub fn new<S: Into<String>>(
+        day: usize,
+        label: S,
+        outcome: std::result::Result<Solution<T, G>, String>,
+    ) -> Self {
+        Self {
+            day,
+            label: label.into(),
+            outcome,
+        }
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        let mut value = Map::new();
+        value.insert("day".into(), json!(self.day));
+        value.insert("label".into(), json!(self.label));
+        match self.outcome {
+            Ok(ref solution) => {
+                if let Value::Object(fields) = serde_json::to_value(solution)? {
+                    value.extend(fields);
+                }
+            }
+            Err(ref e) => {
+                value.insert("error".into(), json!(e));
+            }
+        }
+
+        Ok(Value::Object(value))
+    }
+}
+
+impl OutputFormat {
+    /// Returns [OutputFormat::Json] if the `AOC_OUTPUT_JSON` ENV var is set,
+    /// otherwise [OutputFormat::Plain]. The `Display` implementation of
+    /// [Solution] is always plaintext, so callers wanting the previous
+    /// behavior should format with this explicitly.
+    ///
+    /// ```
+    /// use aoc_helpers::output::OutputFormat;
+    /// use aoc_helpers::Solution;
+    ///
+    /// std::env::set_var("AOC_OUTPUT_JSON", "1");
+    /// let s = Solution::new(1, 2);
+    ///
+    /// assert_eq!(s.to_string(), "part 1: 1\npart 2: 2");
+    /// assert_eq!(
+    ///     s.format(OutputFormat::from_env()).unwrap(),
+    ///     "{\"part_one\":1,\"part_two\":2}"
+    /// );
+    /// ```
+    pub fn from_env() -> Self {
+        if env::var("AOC_OUTPUT_JSON").is_ok() {
+            OutputFormat::Json
+        } else {
+            OutputFormat::Plain
+        }
+    }
+
+    /// Formats a single solution.
+    ///
+    /// ```
+    /// use aoc_helpers::output::OutputFormat;
+    /// use aoc_helpers::Solution;
+    ///
+    /// let s = Solution::new("hello", 5);
+    ///
+    /// assert_eq!(OutputFormat::Plain.format_solution(&s).unwrap(), "part 1: hello\npart 2: 5");
+    /// assert_eq!(
+    ///     OutputFormat::Json.format_solution(&s).unwrap(),
+    ///     "{\"part_one\":\"hello\",\"part_two\":5}"
+    /// );
+    /// assert_eq!(OutputFormat::Csv.format_solution(&s).unwrap(), "part_one,part_two\nhello,5");
+    /// ```
+    #[allow(deprecated)]
+    pub fn format_solution<T, G>(&self, solution: &Solution<T, G>) -> Result<String>
+    where
+        T: Display + Serialize + PartialEq,
+        G: Display + Serialize + PartialEq,
+    {
+        match self {
+            OutputFormat::Plain => Ok(plain(solution)),
+            OutputFormat::Json | OutputFormat::JsonLines => Ok(serde_json::to_string(solution)?),
+            OutputFormat::Csv | OutputFormat::Markdown => {
+                let mut headers = answer_headers(*self);
+                let mut row = vec![solution.part_one.to_string(), solution.part_two.to_string()];
+                if let Some(timings) = solution.timings() {
+                    headers.extend(timing_headers(*self));
+                    row.extend(timing_cells(*self, Some(timings)));
+                }
+
+                Ok(self.table(&headers, &[row]))
+            }
+        }
+    }
+
+    /// Formats the outcomes of many days.
+    ///
+    /// Tabular formats include timing columns if any solution has timings,
+    /// and an error column if any day failed.
+    #[allow(deprecated)]
+    pub fn format_reports<T, G>(&self, reports: &[Report<T, G>]) -> Result<String>
+    where
+        T: Display + Serialize + PartialEq,
+        G: Display + Serialize + PartialEq,
+    {
+        match self {
+            OutputFormat::Plain => Ok(reports
+                .iter()
+                .map(|r| match r.outcome {
+                    Ok(ref solution) => format!("{}\n{}", r.label, plain(solution)),
+                    Err(ref e) => format!("{}\nerror: {}", r.label, e),
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n")),
+            OutputFormat::Json => {
+                let values = reports
+                    .iter()
+                    .map(|r| r.to_json())
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Value::Array(values).to_string())
+            }
+            OutputFormat::JsonLines => Ok(reports
+                .iter()
+                .map(|r| r.to_json().map(|v| v.to_string()))
+                .collect::<Result<Vec<_>>>()?
+                .join("\n")),
+            OutputFormat::Csv | OutputFormat::Markdown => {
+                let timed = reports
+                    .iter()
+                    .any(|r| matches!(r.outcome, Ok(ref s) if s.timings().is_some()));
+                let failed = reports.iter().any(|r| r.outcome.is_err());
+
+                let mut headers = vec!["day".to_string(), "label".to_string()];
+                headers.extend(answer_headers(*self));
+                if timed {
+                    headers.extend(timing_headers(*self));
+                }
+                if failed {
+                    headers.push("error".into());
+                }
+
+                let rows: Vec<Vec<String>> = reports
+                    .iter()
+                    .map(|r| {
+                        let mut row = vec![r.day.to_string(), r.label.clone()];
+                        let (answers, timings, error) = match r.outcome {
+                            Ok(ref s) => (
+                                [s.part_one.to_string(), s.part_two.to_string()],
+                                s.timings(),
+                                String::new(),
+                            ),
+                            Err(ref e) => ([String::new(), String::new()], None, e.clone()),
+                        };
+                        row.extend(answers);
+                        if timed {
+                            row.extend(timing_cells(*self, timings));
+                        }
+                        if failed {
+                            row.push(error);
+                        }
+                        row
+                    })
+                    .collect();
+
+                Ok(self.table(&headers, &rows))
+            }
+        }
+    }
+
+    /// Renders a CSV or Markdown table.
+    fn table(&self, headers: &[String], rows: &[Vec<String>]) -> String {
+        let mut lines = Vec::with_capacity(rows.len() + 2);
+        match self {
+            OutputFormat::Markdown => {
+                let line = |cells: &[String]| {
+                    let cells: Vec<String> = cells.iter().map(|c| markdown_escape(c)).collect();
+                    format!("| {} |", cells.join(" | "))
+                };
+                lines.push(line(headers));
+                lines.push(format!("|{}", " --- |".repeat(headers.len())));
+                lines.extend(rows.iter().map(|r| line(r)));
+            }
+            _ => {
+                let line = |cells: &[String]| {
+                    cells
+                        .iter()
+                        .map(|c| csv_escape(c))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                };
+                lines.push(line(headers));
+                lines.extend(rows.iter().map(|r| line(r)));
+            }
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Formats a solution as plaintext, which unlike the other formats cannot fail.
+#[allow(deprecated)]
+pub(crate) fn plain<T, G>(solution: &Solution<T, G>) -> String
+where
+    T: Display + Serialize + PartialEq,
+    G: Display + Serialize + PartialEq,
+{
+    let mut out = format!(
+        "part 1: {}\npart 2: {}",
+        solution.part_one, solution.part_two
+    );
+    if let Some(timings) = solution.timings() {
+        out.push_str(&format!("\ntimings: {}", timings));
+    }
+    out
+}
+
+fn answer_headers(format: OutputFormat) -> Vec<String> {
+    let headers = match format {
+        OutputFormat::Csv => ["part_one", "part_two"],
+        _ => ["part 1", "part 2"],
+    };
+    headers.iter().map(|h| h.to_string()).collect()
+}
+
+fn timing_headers(format: OutputFormat) -> Vec<String> {
+    let headers = match format {
+        OutputFormat::Csv => ["parse_ns", "part_one_ns", "part_two_ns", "total_ns"],
+        _ => ["parse", "part 1 time", "part 2 time", "total time"],
+    };
+    headers.iter().map(|h| h.to_string()).collect()
+}
+
+fn timing_cells(format: OutputFormat, timings: Option<&Timings>) -> Vec<String> {
+    let cell = |d: Duration| match format {
+        OutputFormat::Csv => nanos(d).to_string(),
+        _ => format!("{:.2?}", d),
+    };
+
+    match timings {
+        Some(t) => vec![
+            cell(t.parse),
+            cell(t.part_one),
+            cell(t.part_two),
+            cell(t.total()),
+        ],
+        None => vec![String::new(); 4],
+    }
+}
+
+fn csv_escape(cell: &str) -> String {
+    if cell.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+fn markdown_escape(cell: &str) -> String {
+    cell.replace('|', "\\|").replace('\n', "<br>")
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use super::*;
+
+    fn reports() -> Vec<Report<i64, String>> {
+        vec![
+            Report::new(1, "001 foo", Ok(Solution::new(12, "a|b".to_string()))),
+            Report::new(
+                2,
+                "002 bar",
+                Ok(
+                    Solution::new(-3, "x,\"y\"".to_string()).with_timings(Timings::new(
+                        Duration::from_micros(1),
+                        Duration::from_micros(2),
+                        Duration::from_micros(3),
+                    )),
+                ),
+            ),
+        ]
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!("JSON".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert_eq!(
+            "jsonl".parse::<OutputFormat>().unwrap(),
+            OutputFormat::JsonLines
+        );
+        assert_eq!(
+            "md".parse::<OutputFormat>().unwrap(),
+            OutputFormat::Markdown
+        );
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn plain() {
+        assert_eq!(
+            OutputFormat::Plain.format_reports(&reports()).unwrap(),
+            [
+                "001 foo",
+                "part 1: 12",
+                "part 2: a|b",
+                "",
+                "002 bar",
+                "part 1: -3",
+                "part 2: x,\"y\"",
+                "timings: parse 1.00µs, part 1 2.00µs, part 2 3.00µs, total 6.00µs",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn json() {
+        let out = OutputFormat::Json.format_reports(&reports()).unwrap();
+        let value: Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(
+            value[0],
+            json!({"day": 1, "label": "001 foo", "part_one": 12, "part_two": "a|b"})
+        );
+        assert_eq!(value[1]["timings"]["total_ns"], json!(6000));
+
+        let out = OutputFormat::JsonLines.format_reports(&reports()).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            serde_json::from_str::<Value>(lines[1]).unwrap()["part_one"],
+            json!(-3)
+        );
+    }
+
+    #[test]
+    fn tables() {
+        assert_eq!(
+            OutputFormat::Csv.format_reports(&reports()).unwrap(),
+            [
+                "day,label,part_one,part_two,parse_ns,part_one_ns,part_two_ns,total_ns",
+                "1,001 foo,12,a|b,,,,",
+                "2,002 bar,-3,\"x,\"\"y\"\"\",1000,2000,3000,6000",
+            ]
+            .join("\n")
+        );
+        assert_eq!(
+            OutputFormat::Markdown.format_reports(&reports()).unwrap(),
+            [
+                "| day | label | part 1 | part 2 | parse | part 1 time | part 2 time | total time |",
+                "| --- | --- | --- | --- | --- | --- | --- | --- |",
+                "| 1 | 001 foo | 12 | a\\|b |  |  |  |  |",
+                "| 2 | 002 bar | -3 | x,\"y\" | 1.00µs | 2.00µs | 3.00µs | 6.00µs |",
+            ]
+            .join("\n")
+        );
+    }
+
+    /// Displays as `?`, but always fails to serialize.
+    #[derive(PartialEq)]
+    struct Unserializable;
+
+    impl Display for Unserializable {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "?")
+        }
+    }
+
+    impl Serialize for Unserializable {
+        fn serialize<S: serde::Serializer>(&self, _: S) -> std::result::Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("unserializable"))
+        }
+    }
+
+    #[test]
+    fn serialization_failures() {
+        let s = Solution::new(Unserializable, 1);
+        assert_eq!(s.to_string(), "part 1: ?\npart 2: 1");
+        assert!(s.format(OutputFormat::Json).is_err());
+        assert_eq!(
+            s.format(OutputFormat::Csv).unwrap(),
+            "part_one,part_two\n?,1"
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+use super::registry::Entry;
+use crate::error::{AocError, Result};
+use crate::solution::{nanos, Timings};
+
+/// Options controlling how many times each day is run when benchmarking.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct BenchOptions {
+    /// The number of measured runs.
+    pub iterations: usize,
+    /// The number of unmeasured runs made before the measured runs.
+    pub warmup: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            iterations: 100,
+            warmup: 5,
+        }
+    }
+}
+
+/// Summary statistics for a set of duration samples.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Computes the statistics for the given samples, or `None` if there are
+    /// no samples.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use aoc_helpers::runner::Stats;
+    ///
+    /// let samples: Vec<Duration> = [2, 4, 4, 4, 5, 5, 7, 9]
+    ///     .iter()
+    ///     .map(|v| Duration::from_micros(*v))
+    ///     .collect();
+    /// let stats = Stats::from_samples(&samples).unwrap();
+    ///
+    /// assert_eq!(stats.mean, Duration::from_micros(5));
+    /// assert_eq!(stats.median, Duration::from_micros(4));
+    /// assert_eq!(stats.min, Duration::from_micros(2));
+    /// assert_eq!(stats.stddev, Duration::from_micros(2));
+    /// ```
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let len = sorted.len() as u128;
+        let mean = sorted.iter().map(|d| d.as_nanos()).sum::<u128>() / len;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_nanos() as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        Some(Self {
+            mean: Duration::from_nanos(mean as u64),
+            median: sorted[(sorted.len() - 1) / 2],
+            min: sorted[0],
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Stats", 4)?;
+        state.serialize_field("mean_ns", &nanos(self.mean))?;
+        state.serialize_field("median_ns", &nanos(self.median))?;
+        state.serialize_field("min_ns", &nanos(self.min))?;
+        state.serialize_field("stddev_ns", &nanos(self.stddev))?;
+        state.end()
+    }
+}
+
+/// The results of benchmarking a single day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Benchmark {
+    pub day: usize,
+    pub label: String,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+    pub total: Stats,
+}
+
+impl Benchmark {
+    /// Computes the statistics for each phase from the given samples.
+    pub fn from_timings(entry: &Entry, samples: &[Timings]) -> Self {
+        let phase = |f: fn(&Timings) -> Duration| {
+            let durations: Vec<Duration> = samples.iter().map(f).collect();
+            Stats::from_samples(&durations).unwrap_or_default()
+        };
+
+        Self {
+            day: entry.day,
+            label: entry.label.clone(),
+            iterations: samples.len(),
+            parse: phase(|t| t.parse),
+            part_one: phase(|t| t.part_one),
+            part_two: phase(|t| t.part_two),
+            total: phase(|t| t.total()),
+        }
+    }
+}
+
+impl Display for Benchmark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} ({} iterations)", self.label, self.iterations)?;
+        write!(
+            f,
+            "{:<10}{:>12}{:>12}{:>12}{:>12}",
+            "phase", "mean", "median", "min", "stddev"
+        )?;
+        for (name, stats) in [
+            ("parse", &self.parse),
+            ("part 1", &self.part_one),
+            ("part 2", &self.part_two),
+            ("total", &self.total),
+        ] {
+            write!(
+                f,
+                "\n{:<10}{:>12}{:>12}{:>12}{:>12}",
+                name,
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.stddev),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Loads the input for the given entry once, then solves it repeatedly,
+/// timing each phase separately. At least one iteration is required, and the
+/// entry must record [Timings] for each measured run.
+pub fn bench_entry(entry: &Entry, opts: &BenchOptions) -> Result<Benchmark> {
+    if opts.iterations == 0 {
+        return Err(AocError::UsageError(
+            "at least one iteration is required".into(),
+        ));
+    }
+    let input = entry.load_input()?;
+
+    for _ in 0..opts.warmup {
+        entry.solve(&input)?;
+    }
+
+    let samples = (0..opts.iterations)
+        .map(|_| {
+            entry.solve_timed(&input).and_then(|s| {
+                s.timings().copied().ok_or_else(|| {
+                    AocError::UsageError(format!("{} did not record timings", entry.label))
+                })
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Benchmark::from_timings(entry, &samples))
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use super::*;
+    use crate::runner::Answer;
+    use crate::Solution;
+
+    #[test]
+    fn stats() {
+        assert_eq!(Stats::from_samples(&[]), None);
+
+        let one = Stats::from_samples(&[Duration::from_millis(3)]).unwrap();
+        assert_eq!(one.mean, Duration::from_millis(3));
+        assert_eq!(one.median, Duration::from_millis(3));
+        assert_eq!(one.stddev, Duration::from_secs(0));
+
+        let samples: Vec<Duration> = [5, 1, 3, 100]
+            .iter()
+            .map(|v| Duration::from_nanos(*v))
+            .collect();
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(3));
+        assert_eq!(
+            serde_json::to_value(stats).unwrap()["min_ns"],
+            serde_json::json!(1)
+        );
+    }
+
+    #[test]
+    fn benching() {
+        let entry = Entry::new(
+            2,
+            "002 untimed".into(),
+            || Ok(String::new()),
+            |_, _| Ok(Solution::new(Answer::new(&1), Answer::new(&2))),
+        );
+        let opts = BenchOptions {
+            iterations: 2,
+            warmup: 0,
+        };
+        assert_eq!(
+            bench_entry(&entry, &opts).unwrap_err().to_string(),
+            "invalid usage: 002 untimed did not record timings"
+        );
+
+        let opts = BenchOptions {
+            iterations: 0,
+            warmup: 0,
+        };
+        assert_eq!(
+            bench_entry(&entry, &opts).unwrap_err().to_string(),
+            "invalid usage: at least one iteration is required"
+        );
+    }
+
+    #[test]
+    fn display() {
+        let stats = |ms| Stats {
+            mean: Duration::from_millis(ms),
+            median: Duration::from_millis(ms),
+            min: Duration::from_millis(ms),
+            stddev: Duration::from_micros(1),
+        };
+        let bench = Benchmark {
+            day: 1,
+            label: "001 foo".into(),
+            iterations: 10,
+            parse: stats(1),
+            part_one: stats(2),
+            part_two: stats(3),
+            total: stats(6),
+        };
+
+        let expected = [
+            "001 foo (10 iterations)",
+            "phase             mean      median         min      stddev",
+            "parse           1.00ms      1.00ms      1.00ms      1.00µs",
+            "part 1          2.00ms      2.00ms      2.00ms      1.00µs",
+            "part 2          3.00ms      3.00ms      3.00ms      1.00µs",
+            "total           6.00ms      6.00ms      6.00ms      1.00µs",
+        ]
+        .join("\n");
+        assert_eq!(bench.to_string(), expected);
+    }
+}
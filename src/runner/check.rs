@@ -0,0 +1,223 @@
+use serde::Deserialize;
+use serde_json::Value;
+use std::fmt::{self, Display};
+
+use super::registry::{display_value, Answer, Entry};
+use crate::error::{AocError, Result};
+
+/// The expected answers for a day, as read from an `expected.json` file like
+///
+/// ```json
+/// { "part_one": 1234, "part_two": "ABCD" }
+/// ```
+///
+/// Either part may be omitted or `null` if its answer is not yet known.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct Expected {
+    #[serde(default)]
+    pub part_one: Option<Value>,
+    #[serde(default)]
+    pub part_two: Option<Value>,
+}
+
+impl Expected {
+    pub fn parse(input: &str) -> Result<Self> {
+        Ok(serde_json::from_str(input)?)
+    }
+
+    /// Loads the expected answers for the given entry. A missing file is
+    /// treated as having no expected answers.
+    pub fn load(entry: &Entry) -> Result<Self> {
+        match entry.load_expected() {
+            Ok(input) => Self::parse(&input),
+            Err(AocError::InputMissing(_)) => Ok(Self::default()),
+            Err(AocError::IOError(ref e)) if e.kind() == std::io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// The result of checking a single part against its expected answer.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Status {
+    /// The answer matched the expected answer.
+    Ok,
+    /// The answer did not match the expected answer.
+    Mismatch,
+    /// There was no expected answer to compare against.
+    Unknown,
+    /// The answers or expected answers could not be produced.
+    Error,
+}
+
+impl Status {
+    /// Whether or not this status should be considered a failure.
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Mismatch | Status::Error)
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Mismatch => write!(f, "MISMATCH"),
+            Status::Unknown => write!(f, "unknown"),
+            Status::Error => write!(f, "ERROR"),
+        }
+    }
+}
+
+/// A single row of a check report.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub label: String,
+    /// The part this row describes, or `None` if the whole day failed.
+    pub part: Option<usize>,
+    pub status: Status,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// Runs the given entry and compares both parts against its expected answers.
+#[allow(deprecated)]
+pub fn check_entry(entry: &Entry) -> Vec<Check> {
+    let failed = |e: AocError| {
+        vec![Check {
+            label: entry.label.clone(),
+            part: None,
+            status: Status::Error,
+            expected: String::new(),
+            actual: e.to_string(),
+        }]
+    };
+
+    let expected = match Expected::load(entry) {
+        Ok(expected) => expected,
+        Err(e) => return failed(e),
+    };
+
+    let solution = match entry.run(false) {
+        Ok(solution) => solution,
+        Err(e) => return failed(e),
+    };
+
+    vec![
+        compare(entry, 1, &solution.part_one, expected.part_one.as_ref()),
+        compare(entry, 2, &solution.part_two, expected.part_two.as_ref()),
+    ]
+}
+
+fn compare(entry: &Entry, part: usize, actual: &Answer, expected: Option<&Value>) -> Check {
+    let (status, expected) = match expected {
+        None | Some(Value::Null) => (Status::Unknown, String::new()),
+        Some(v) if actual.matches(v) => (Status::Ok, display_value(v)),
+        Some(v) => (Status::Mismatch, display_value(v)),
+    };
+
+    Check {
+        label: entry.label.clone(),
+        part: Some(part),
+        status,
+        expected,
+        actual: actual.to_string(),
+    }
+}
+
+/// Renders the given checks as an aligned plaintext table.
+pub fn render_table(checks: &[Check]) -> String {
+    let headers = ["day", "part", "status", "expected", "actual"];
+    let rows: Vec<[String; 5]> = checks
+        .iter()
+        .map(|c| {
+            [
+                c.label.clone(),
+                c.part.map(|p| p.to_string()).unwrap_or_else(|| "-".into()),
+                c.status.to_string(),
+                c.expected.clone(),
+                c.actual.clone(),
+            ]
+        })
+        .collect();
+
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
+    for row in &rows {
+        for (w, cell) in widths.iter_mut().zip(row.iter()) {
+            *w = (*w).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, w)| format!("{:<width$}", cell, width = w))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![line(headers.to_vec())];
+    lines.extend(
+        rows.iter()
+            .map(|row| line(row.iter().map(|c| c.as_str()).collect())),
+    );
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parsing() {
+        let expected = Expected::parse(r#"{"part_one": 12, "part_two": "ab"}"#).unwrap();
+        assert_eq!(expected.part_one, Some(json!(12)));
+        assert_eq!(expected.part_two, Some(json!("ab")));
+
+        let expected = Expected::parse(r#"{"part_one": 12}"#).unwrap();
+        assert_eq!(expected.part_two, None);
+
+        assert!(Expected::parse("12").is_err());
+    }
+
+    #[test]
+    fn table() {
+        let checks = vec![
+            Check {
+                label: "001 foo".into(),
+                part: Some(1),
+                status: Status::Ok,
+                expected: "12".into(),
+                actual: "12".into(),
+            },
+            Check {
+                label: "001 foo".into(),
+                part: Some(2),
+                status: Status::Mismatch,
+                expected: "abc".into(),
+                actual: "abd".into(),
+            },
+            Check {
+                label: "012 a longer name".into(),
+                part: None,
+                status: Status::Error,
+                expected: String::new(),
+                actual: "broken".into(),
+            },
+        ];
+
+        let expected = [
+            "day                part  status    expected  actual",
+            "001 foo            1     ok        12        12",
+            "001 foo            2     MISMATCH  abc       abd",
+            "012 a longer name  -     ERROR               broken",
+        ]
+        .join("\n");
+        assert_eq!(render_table(&checks), expected);
+    }
+}
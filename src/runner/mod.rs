@@ -0,0 +1,600 @@
+//! A runner for executing many days of [TrySolver](crate::TrySolver)
+//! implementations from a single binary.
+//!
+//! Solvers are collected into a [Registry] with [aoc_registry](crate::aoc_registry),
+//! and the [aoc_main](crate::aoc_main) macro generates a `main` function that
+//! parses the command line and runs the selected days:
+//!
+//! ```text
+//! aoc list                 list the registered days
+//! aoc [run] [DAYS]         run `all` days (the default), a day `5`, or a range `3-7`
+//!     --format FORMAT      print solutions as plain, json, jsonl, csv or markdown
+//!     --json               the same as `--format jsonl`
+//!     --time               include parse and part timings
+//! aoc check [DAYS]         compare answers against each day's `expected.json`
+//! aoc bench [DAYS]         time each phase over many runs
+//!     --iterations N       the number of measured runs (default 100)
+//!     --warmup N           the number of unmeasured runs beforehand (default 5)
+//!     --json               print each benchmark as JSON
+//! ```
+use serde_json::json;
+use std::env;
+use std::io::{self, Write};
+
+use crate::error::{AocError, Result};
+use crate::output::{OutputFormat, Report};
+
+pub use self::bench::{BenchOptions, Benchmark, Stats};
+pub use self::check::{Check, Expected, Status};
+pub use self::registry::{Answer, Entry, Registry, Selection};
+
+mod bench;
+mod check;
+mod registry;
+
+const USAGE: &str = "\
+usage: <command> [options] [days]
+
+commands:
+    list        list the registered days
+    run         run the selected days (the default)
+    check       compare the answers of the selected days against the
+                `expected.json` file found alongside each day's input
+    bench       time the parse and part phases of the selected days over
+                many runs
+    help        print this message
+
+options:
+    --format FORMAT     (run, bench) print results as `plain` (the default), `json`, `jsonl`,
+                        `csv` or `markdown`. Benchmarks support `plain`, `json`
+                        and `jsonl`
+    --json              (run, bench) the same as `--format jsonl` (also enabled
+                        by AOC_OUTPUT_JSON)
+    --time              include parse and part timings when running
+    --iterations N      (bench) the number of measured runs, at least 1, default 100
+    --warmup N          (bench) the number of unmeasured runs beforehand, default 5
+
+days:
+    `all` (the default), a single day like `5`, or a range like `3-7`. Multiple
+    selections may be separated by commas or spaces.";
+
+/// The action requested on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Help,
+    List,
+    Run(RunOptions),
+    Check(RunOptions),
+    Bench(RunOptions, BenchOptions),
+}
+
+/// Options controlling how the selected days are run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunOptions {
+    pub selection: Selection,
+    pub format: OutputFormat,
+    pub timed: bool,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            selection: Selection::All,
+            format: OutputFormat::Plain,
+            timed: false,
+        }
+    }
+}
+
+impl Command {
+    /// Parses a command from the given arguments, excluding the program name.
+    ///
+    /// ```
+    /// use aoc_helpers::output::OutputFormat;
+    /// use aoc_helpers::runner::{Command, RunOptions, Selection};
+    ///
+    /// assert_eq!(Command::parse(&["list"]).unwrap(), Command::List);
+    /// assert_eq!(
+    ///     Command::parse(&["3-4", "--time"]).unwrap(),
+    ///     Command::Run(RunOptions {
+    ///         selection: Selection::Days(vec![(3, 4)]),
+    ///         format: OutputFormat::Plain,
+    ///         timed: true,
+    ///     })
+    /// );
+    /// ```
+    pub fn parse<S: AsRef<str>>(args: &[S]) -> Result<Self> {
+        let mut args = args.iter().map(|a| a.as_ref()).peekable();
+
+        let mut subcommand = "run";
+        match args.peek() {
+            Some(&"list") => return Ok(Command::List),
+            Some(&"help") | Some(&"-h") | Some(&"--help") => return Ok(Command::Help),
+            Some(&cmd) if cmd == "run" || cmd == "check" || cmd == "bench" => {
+                subcommand = cmd;
+                args.next();
+            }
+            _ => {}
+        }
+
+        let mut opts = RunOptions::default();
+        let mut bench = BenchOptions::default();
+        let mut selection: Option<Selection> = None;
+        while let Some(arg) = args.next() {
+            match arg {
+                "--json" | "--format" | "--time" if subcommand == "check" => {
+                    return Err(AocError::UsageError(format!(
+                        "{} is not supported by check",
+                        arg
+                    )))
+                }
+                "--json" => opts.format = OutputFormat::JsonLines,
+                "--format" => {
+                    opts.format = args
+                        .next()
+                        .ok_or_else(|| AocError::UsageError(format!("{} requires a value", arg)))?
+                        .parse()?
+                }
+                "--time" => opts.timed = true,
+                "--iterations" | "--warmup" if subcommand == "bench" => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| AocError::UsageError(format!("{} requires a value", arg)))?
+                        .parse()?;
+                    if arg == "--iterations" {
+                        if value == 0 {
+                            return Err(AocError::UsageError(
+                                "--iterations must be at least 1".into(),
+                            ));
+                        }
+                        bench.iterations = value;
+                    } else {
+                        bench.warmup = value;
+                    }
+                }
+                "-h" | "--help" => return Ok(Command::Help),
+                _ if arg.starts_with('-') => {
+                    return Err(AocError::UsageError(format!("unknown option: {}", arg)))
+                }
+                _ => {
+                    let s = Selection::parse(arg)?;
+                    selection = Some(match selection {
+                        Some(existing) => existing.union(s),
+                        None => s,
+                    });
+                }
+            }
+        }
+
+        opts.selection = selection.unwrap_or(Selection::All);
+        match subcommand {
+            "check" => Ok(Command::Check(opts)),
+            "bench" => Ok(Command::Bench(opts, bench)),
+            _ => Ok(Command::Run(opts)),
+        }
+    }
+}
+
+/// Runs the given [Command] against the registry, writing results to `out`.
+/// Returns the status code the process should exit with: `0` if every
+/// selected day succeeded, or `1` if any failed or, when checking, produced
+/// an answer that did not match the expected answer.
+pub fn run<W: Write>(registry: &Registry, command: &Command, out: &mut W) -> Result<i32> {
+    match command {
+        Command::Help => writeln!(out, "{}", USAGE)?,
+        Command::List => {
+            for label in registry.labels() {
+                writeln!(out, "{}", label)?;
+            }
+        }
+        Command::Run(opts) => return run_days(registry, opts, out),
+        Command::Check(opts) => return check_days(registry, opts, out),
+        Command::Bench(opts, bench) => return bench_days(registry, opts, bench, out),
+    }
+
+    Ok(0)
+}
+
+/// Parses the process arguments and runs the resulting [Command], printing to
+/// stdout. Errors in the arguments are printed to stderr along with the usage.
+/// Returns the status code the process should exit with.
+pub fn main(registry: &Registry) -> i32 {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut command = match Command::parse(&args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return 2;
+        }
+    };
+
+    if let Command::Run(ref mut opts) | Command::Bench(ref mut opts, _) = command {
+        if opts.format == OutputFormat::Plain && env::var("AOC_OUTPUT_JSON").is_ok() {
+            opts.format = OutputFormat::JsonLines;
+        }
+    }
+
+    let stdout = io::stdout();
+    match run(registry, &command, &mut stdout.lock()) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("{}", e);
+            2
+        }
+    }
+}
+
+fn selected<'a>(registry: &'a Registry, opts: &RunOptions) -> Result<Vec<&'a Entry>> {
+    let entries = registry.select(&opts.selection);
+    if entries.is_empty() {
+        return Err(AocError::UsageError(
+            "no registered days match the selection".into(),
+        ));
+    }
+
+    Ok(entries)
+}
+
+fn check_days<W: Write>(registry: &Registry, opts: &RunOptions, out: &mut W) -> Result<i32> {
+    let checks: Vec<Check> = selected(registry, opts)?
+        .into_iter()
+        .flat_map(check::check_entry)
+        .collect();
+
+    let count = |status: Status| checks.iter().filter(|c| c.status == status).count();
+    writeln!(out, "{}", check::render_table(&checks))?;
+    writeln!(
+        out,
+        "\n{} ok, {} mismatched, {} unknown, {} errors",
+        count(Status::Ok),
+        count(Status::Mismatch),
+        count(Status::Unknown),
+        count(Status::Error)
+    )?;
+
+    if checks.iter().any(|c| c.status.is_failure()) {
+        Ok(1)
+    } else {
+        Ok(0)
+    }
+}
+
+fn bench_days<W: Write>(
+    registry: &Registry,
+    opts: &RunOptions,
+    bench: &BenchOptions,
+    out: &mut W,
+) -> Result<i32> {
+    let mut code = 0;
+    let mut values = Vec::new();
+    for (i, entry) in selected(registry, opts)?.iter().enumerate() {
+        let res = bench::bench_entry(entry, bench);
+        match opts.format {
+            OutputFormat::Json | OutputFormat::JsonLines => {
+                let value = match res {
+                    Ok(ref benchmark) => json!(benchmark),
+                    Err(ref e) => json!({
+                        "day": entry.day,
+                        "label": entry.label,
+                        "error": e.to_string(),
+                    }),
+                };
+                if opts.format == OutputFormat::Json {
+                    values.push(value);
+                } else {
+                    writeln!(out, "{}", value)?;
+                }
+            }
+            _ => {
+                if i > 0 {
+                    writeln!(out)?;
+                }
+                match res {
+                    Ok(ref benchmark) => writeln!(out, "{}", benchmark)?,
+                    Err(ref e) => writeln!(out, "{}\nerror: {}", entry.label, e)?,
+                }
+            }
+        }
+
+        if res.is_err() {
+            code = 1;
+        }
+    }
+
+    if opts.format == OutputFormat::Json {
+        writeln!(out, "{}", serde_json::Value::Array(values))?;
+    }
+
+    Ok(code)
+}
+
+#[allow(deprecated)]
+fn run_days<W: Write>(registry: &Registry, opts: &RunOptions, out: &mut W) -> Result<i32> {
+    let reports: Vec<Report<Answer, Answer>> = selected(registry, opts)?
+        .into_iter()
+        .map(|entry| {
+            let outcome = entry.run(opts.timed).map_err(|e| e.to_string());
+            Report::new(entry.day, entry.label.clone(), outcome)
+        })
+        .collect();
+
+    writeln!(out, "{}", opts.format.format_reports(&reports)?)?;
+
+    if reports.iter().any(|r| r.outcome.is_err()) {
+        Ok(1)
+    } else {
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_registry;
+    use crate::solution::TrySolver;
+
+    pub struct Sum(Vec<i64>);
+
+    impl TrySolver<'_> for Sum {
+        const ID: &'static str = "sum";
+        const DAY: usize = 3;
+
+        type P1 = i64;
+        type P2 = String;
+        type Error = String;
+
+        fn parse(input: &str) -> std::result::Result<Self, Self::Error> {
+            input
+                .split_whitespace()
+                .map(|v| v.parse().map_err(|_| format!("bad value: {}", v)))
+                .collect::<std::result::Result<_, _>>()
+                .map(Sum)
+        }
+
+        fn part_one(&mut self) -> std::result::Result<Self::P1, Self::Error> {
+            Ok(self.0.iter().sum())
+        }
+
+        fn part_two(&mut self) -> std::result::Result<Self::P2, Self::Error> {
+            Ok(format!("{} values", self.0.len()))
+        }
+    }
+
+    pub struct Echo<'a>(&'a str);
+
+    impl<'a> TrySolver<'a> for Echo<'a> {
+        const ID: &'static str = "echo";
+        const DAY: usize = 1;
+
+        type P1 = &'a str;
+        type P2 = usize;
+        type Error = String;
+
+        fn parse(input: &'a str) -> std::result::Result<Self, Self::Error> {
+            Ok(Echo(input.trim()))
+        }
+
+        fn part_one(&mut self) -> std::result::Result<Self::P1, Self::Error> {
+            Ok(self.0)
+        }
+
+        fn part_two(&mut self) -> std::result::Result<Self::P2, Self::Error> {
+            Ok(self.0.len())
+        }
+    }
+
+    fn build(sum_input: fn() -> Result<String>) -> Registry {
+        let entries = aoc_registry![Sum, Echo]
+            .entries()
+            .iter()
+            .map(|e| match e.day {
+                1 => e.clone().with_loader(|| Ok("hello".to_string())),
+                _ => e.clone().with_loader(sum_input),
+            })
+            .collect();
+        Registry::new(entries)
+    }
+
+    fn output(registry: &Registry, args: &[&str]) -> (i32, String) {
+        let mut out = Vec::new();
+        let code = run(registry, &Command::parse(args).unwrap(), &mut out).unwrap();
+        (code, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(
+            Command::parse::<&str>(&[]).unwrap(),
+            Command::Run(RunOptions::default())
+        );
+        assert_eq!(Command::parse(&["--help"]).unwrap(), Command::Help);
+        assert_eq!(
+            Command::parse(&["run", "1", "3-4,9", "--json"]).unwrap(),
+            Command::Run(RunOptions {
+                selection: Selection::Days(vec![(1, 1), (3, 4), (9, 9)]),
+                format: OutputFormat::JsonLines,
+                timed: false,
+            })
+        );
+        assert!(Command::parse(&["--bogus"]).is_err());
+        assert!(Command::parse(&["x"]).is_err());
+    }
+
+    #[test]
+    fn listing() {
+        let registry = build(|| Ok("1 2 3".to_string()));
+        assert_eq!(registry.labels(), vec!["001 echo", "003 sum"]);
+        assert_eq!(
+            output(&registry, &["list"]),
+            (0, "001 echo\n003 sum\n".to_string())
+        );
+    }
+
+    #[test]
+    fn running() {
+        let registry = build(|| Ok("1 2 3".to_string()));
+        assert_eq!(
+            output(&registry, &[]),
+            (
+                0,
+                "001 echo\npart 1: hello\npart 2: 5\n\n003 sum\npart 1: 6\npart 2: 3 values\n"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            output(&registry, &["3", "--json"]),
+            (
+                0,
+                "{\"day\":3,\"label\":\"003 sum\",\"part_one\":6,\"part_two\":\"3 values\"}\n"
+                    .to_string()
+            )
+        );
+
+        assert_eq!(
+            output(&registry, &["--format", "csv"]),
+            (
+                0,
+                "day,label,part_one,part_two\n1,001 echo,hello,5\n3,003 sum,6,3 values\n"
+                    .to_string()
+            )
+        );
+        let (_, out) = output(&registry, &["--format", "json"]);
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value[1]["part_two"], "3 values");
+        assert!(Command::parse(&["--format", "xml"]).is_err());
+
+        let (code, out) = output(&registry, &["--time", "1"]);
+        assert_eq!(code, 0);
+        assert!(out.contains("timings: parse "));
+
+        let mut out = Vec::new();
+        let command = Command::parse(&["5-9"]).unwrap();
+        assert!(run(&registry, &command, &mut out).is_err());
+    }
+
+    #[test]
+    fn failures() {
+        let registry = build(|| Ok("1 x 3".to_string()));
+        let (code, out) = output(&registry, &["all"]);
+        assert_eq!(code, 1);
+        assert!(out.ends_with("003 sum\nerror: 003 sum failed during parse: bad value: x\n"));
+
+        let registry = build(|| Err(AocError::InputMissing("003".into())));
+        let (code, out) = output(&registry, &["3", "--json"]);
+        assert_eq!(code, 1);
+        assert_eq!(
+            out,
+            "{\"day\":3,\"error\":\"specified input was missing: 003\",\"label\":\"003 sum\"}\n"
+        );
+    }
+
+    #[test]
+    fn checking() {
+        let registry = Registry::new(
+            build(|| Ok("1 2 3".to_string()))
+                .entries()
+                .iter()
+                .map(|e| match e.day {
+                    1 => e.clone().with_expected_loader(|| {
+                        Ok(r#"{"part_one": "hello", "part_two": 4}"#.to_string())
+                    }),
+                    _ => e
+                        .clone()
+                        .with_expected_loader(|| Ok(r#"{"part_one": "6"}"#.to_string())),
+                })
+                .collect(),
+        );
+
+        assert!(matches!(
+            Command::parse(&["check", "1"]).unwrap(),
+            Command::Check(_)
+        ));
+        for option in &["--json", "--time", "--format"] {
+            assert_eq!(
+                Command::parse(&["check", option, "csv"])
+                    .unwrap_err()
+                    .to_string(),
+                format!("invalid usage: {} is not supported by check", option)
+            );
+        }
+
+        let (code, out) = output(&registry, &["check"]);
+        assert_eq!(code, 1);
+        assert_eq!(
+            out,
+            [
+                "day       part  status    expected  actual",
+                "001 echo  1     ok        hello     hello",
+                "001 echo  2     MISMATCH  4         5",
+                "003 sum   1     ok        6         6",
+                "003 sum   2     unknown             3 values",
+                "",
+                "2 ok, 1 mismatched, 1 unknown, 0 errors",
+                "",
+            ]
+            .join("\n")
+        );
+
+        let (code, _) = output(&registry, &["check", "3"]);
+        assert_eq!(code, 0);
+
+        let registry = Registry::new(
+            registry
+                .entries()
+                .iter()
+                .map(|e| e.clone().with_expected_loader(|| Ok("{".to_string())))
+                .collect(),
+        );
+        let (code, out) = output(&registry, &["check", "3"]);
+        assert_eq!(code, 1);
+        assert!(out.contains("003 sum  -     ERROR"));
+    }
+
+    #[test]
+    fn benchmarking() {
+        assert_eq!(
+            Command::parse(&["bench", "3", "--iterations", "7", "--warmup", "0"]).unwrap(),
+            Command::Bench(
+                RunOptions {
+                    selection: Selection::Days(vec![(3, 3)]),
+                    format: OutputFormat::Plain,
+                    timed: false,
+                },
+                BenchOptions {
+                    iterations: 7,
+                    warmup: 0,
+                }
+            )
+        );
+        assert!(Command::parse(&["run", "--iterations", "7"]).is_err());
+        assert!(Command::parse(&["bench", "--iterations"]).is_err());
+        assert!(Command::parse(&["bench", "--warmup", "x"]).is_err());
+        assert_eq!(
+            Command::parse(&["bench", "--iterations", "0"])
+                .unwrap_err()
+                .to_string(),
+            "invalid usage: --iterations must be at least 1"
+        );
+
+        let registry = build(|| Ok("1 2 3".to_string()));
+        let (code, out) = output(&registry, &["bench", "--iterations", "3"]);
+        assert_eq!(code, 0);
+        assert!(out.starts_with("001 echo (3 iterations)\nphase"));
+        assert!(out.contains("\n\n003 sum (3 iterations)\n"));
+
+        let (code, out) = output(&registry, &["bench", "3", "--iterations", "2", "--json"]);
+        assert_eq!(code, 0);
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value["label"], "003 sum");
+        assert_eq!(value["iterations"], 2);
+        assert!(value["part_two"]["median_ns"].is_u64());
+
+        let registry = build(|| Ok("x".to_string()));
+        let (code, out) = output(&registry, &["bench", "3"]);
+        assert_eq!(code, 1);
+        assert_eq!(
+            out,
+            "003 sum\nerror: 003 sum failed during parse: bad value: x\n"
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+use std::fmt::{self, Display};
+
+use crate::error::Result;
+#[allow(deprecated)]
+use crate::solution::Solution;
+use crate::util::load_named_raw_input;
+
+/// Builds a [Registry] from a list of [TrySolver](crate::TrySolver) types.
+/// Solvers that borrow from their input may be listed without their lifetime.
+/// Line-based [Solver](crate::Solver) implementations can be registered via
+/// the [Lines](crate::solution::Lines) adapter, and
+/// [SharedSolver](crate::SharedSolver) implementations via the
+/// [Shared](crate::solution::Shared) adapter.
+///
+/// # Usage
+///
+/// ```
+/// use aoc_helpers::aoc_registry;
+/// use aoc_helpers::TrySolver;
+///
+/// struct Words<'a>(Vec<&'a str>);
+///
+/// impl<'a> TrySolver<'a> for Words<'a> {
+///     const ID: &'static str = "words";
+///     const DAY: usize = 2;
+///
+///     type P1 = usize;
+///     type P2 = &'a str;
+///     type Error = String;
+///
+///     fn parse(input: &'a str) -> Result<Self, Self::Error> {
+///         Ok(Words(input.split_whitespace().collect()))
+///     }
+///
+///     fn part_one(&mut self) -> Result<Self::P1, Self::Error> {
+///         Ok(self.0.len())
+///     }
+///
+///     fn part_two(&mut self) -> Result<Self::P2, Self::Error> {
+///         self.0.last().copied().ok_or_else(|| "no words".into())
+///     }
+/// }
+///
+/// let registry = aoc_registry![Words];
+/// let entry = registry.get(2).unwrap();
+///
+/// assert_eq!(entry.label, "002 words");
+/// assert_eq!(entry.solve("foo bar").unwrap().to_string(), "part 1: 2\npart 2: bar");
+/// ```
+#[macro_export]
+macro_rules! aoc_registry {
+    ($($solver:ty),* $(,)?) => {
+        $crate::runner::Registry::new(vec![$(
+            $crate::runner::Entry::new(
+                <$solver as $crate::TrySolver>::DAY,
+                <$solver as $crate::TrySolver>::solver_label(),
+                <$solver as $crate::TrySolver>::load_input,
+                |input: &str, timed: bool| {
+                    let solution = if timed {
+                        <$solver as $crate::TrySolver>::solve_timed(input)
+                    } else {
+                        <$solver as $crate::TrySolver>::solve_from(input)
+                    };
+                    solution.map($crate::runner::Answer::erase)
+                },
+            )
+        ),*])
+    };
+}
+
+/// Generates a `main` function that runs the given solvers via
+/// [runner::main](crate::runner::main), exiting with its status code.
+///
+/// ```ignore
+/// // src/bin/aoc.rs
+/// aoc_helpers::aoc_main!(Day001, Day002, Lines<Day003>);
+/// ```
+#[macro_export]
+macro_rules! aoc_main {
+    ($($solver:ty),* $(,)?) => {
+        fn main() {
+            let registry = $crate::aoc_registry![$($solver),*];
+            std::process::exit($crate::runner::main(&registry));
+        }
+    };
+}
+
+/// A type-erased answer to one part of a puzzle, retaining both its plaintext
+/// and JSON representations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    text: String,
+    value: Value,
+}
+
+impl Answer {
+    pub fn new<T: Display + Serialize>(answer: &T) -> Self {
+        let text = answer.to_string();
+        let value = serde_json::to_value(answer).unwrap_or_else(|_| Value::String(text.clone()));
+        Self { text, value }
+    }
+
+    /// The JSON representation of this answer.
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// Whether or not this answer matches the `expected` value. Answers match
+    /// if their JSON representations are equal, or if `expected` is written
+    /// the same way as the plaintext representation of this answer, so that
+    /// `"123"` and `123` are both accepted for a numeric answer.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use aoc_helpers::runner::Answer;
+    ///
+    /// assert!(Answer::new(&123).matches(&json!(123)));
+    /// assert!(Answer::new(&123).matches(&json!("123")));
+    /// assert!(Answer::new(&"123").matches(&json!(123)));
+    /// assert!(!Answer::new(&"abc").matches(&json!("abd")));
+    /// ```
+    pub fn matches(&self, expected: &Value) -> bool {
+        self.value == *expected || display_value(expected) == self.text
+    }
+
+    /// Erases the types of the answers of the given [Solution], keeping any
+    /// timings.
+    #[allow(deprecated)]
+    pub fn erase<T, G>(solution: Solution<T, G>) -> Solution<Answer, Answer>
+    where
+        T: Display + Serialize + PartialEq,
+        G: Display + Serialize + PartialEq,
+    {
+        let erased = Solution::new(
+            Answer::new(&solution.part_one),
+            Answer::new(&solution.part_two),
+        );
+        match solution.timings() {
+            Some(timings) => erased.with_timings(*timings),
+            None => erased,
+        }
+    }
+}
+
+/// Displays JSON values, leaving strings without their surrounding quotes.
+pub(super) fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+/// A single registered solver, usually produced by [aoc_registry].
+#[derive(Debug, Clone)]
+#[allow(deprecated)]
+pub struct Entry {
+    pub day: usize,
+    pub label: String,
+    load: fn() -> Result<String>,
+    solve: fn(&str, bool) -> Result<Solution<Answer, Answer>>,
+    expected: Option<fn() -> Result<String>>,
+}
+
+impl Entry {
+    #[allow(deprecated)]
+    pub fn new(
+        day: usize,
+        label: String,
+        load: fn() -> Result<String>,
+        solve: fn(&str, bool) -> Result<Solution<Answer, Answer>>,
+    ) -> Self {
+        Self {
+            day,
+            label,
+            load,
+            solve,
+            expected: None,
+        }
+    }
+
+    /// Replaces the function used to load the input for this entry.
+    pub fn with_loader(mut self, load: fn() -> Result<String>) -> Self {
+        self.load = load;
+        self
+    }
+
+    /// Replaces the function used to load the expected answers for this entry.
+    pub fn with_expected_loader(mut self, expected: fn() -> Result<String>) -> Self {
+        self.expected = Some(expected);
+        self
+    }
+
+    pub fn load_input(&self) -> Result<String> {
+        (self.load)()
+    }
+
+    /// Loads the contents of the expected answers file for this entry. Unless
+    /// replaced via [with_expected_loader](Entry::with_expected_loader), this
+    /// is the `expected.json` file found alongside the day's input, in the
+    /// same way as [load_named_raw_input].
+    pub fn load_expected(&self) -> Result<String> {
+        match self.expected {
+            Some(expected) => expected(),
+            None => load_named_raw_input(&format!("{:03}", self.day), "expected.json"),
+        }
+    }
+
+    /// Solves both parts for the given input.
+    #[allow(deprecated)]
+    pub fn solve(&self, input: &str) -> Result<Solution<Answer, Answer>> {
+        (self.solve)(input, false)
+    }
+
+    /// Solves both parts for the given input, including [Timings](crate::solution::Timings).
+    #[allow(deprecated)]
+    pub fn solve_timed(&self, input: &str) -> Result<Solution<Answer, Answer>> {
+        (self.solve)(input, true)
+    }
+
+    /// Loads the input for this entry, then solves both parts.
+    #[allow(deprecated)]
+    pub fn run(&self, timed: bool) -> Result<Solution<Answer, Answer>> {
+        let input = self.load_input()?;
+        (self.solve)(&input, timed)
+    }
+}
+
+/// A collection of [Entry] values, ordered by day.
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    entries: Vec<Entry>,
+}
+
+impl Registry {
+    pub fn new(mut entries: Vec<Entry>) -> Self {
+        entries.sort_by_key(|e| e.day);
+        Self { entries }
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn get(&self, day: usize) -> Option<&Entry> {
+        self.entries.iter().find(|e| e.day == day)
+    }
+
+    /// The labels of every registered entry, in order.
+    pub fn labels(&self) -> Vec<&str> {
+        self.entries.iter().map(|e| e.label.as_str()).collect()
+    }
+
+    /// Returns the entries whose days fall within the given [Selection].
+    pub fn select(&self, selection: &Selection) -> Vec<&Entry> {
+        self.entries
+            .iter()
+            .filter(|e| selection.contains(e.day))
+            .collect()
+    }
+}
+
+/// A set of days to run, as parsed from arguments like `all`, `5` or `3-7`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selection {
+    All,
+    Days(Vec<(usize, usize)>),
+}
+
+impl Selection {
+    /// Parses a selection from a comma-separated list of days and inclusive
+    /// ranges of days.
+    ///
+    /// ```
+    /// use aoc_helpers::runner::Selection;
+    ///
+    /// let s = Selection::parse("1,3-5").unwrap();
+    /// assert!(s.contains(4));
+    /// assert!(!s.contains(2));
+    /// assert_eq!(Selection::parse("all").unwrap(), Selection::All);
+    /// assert!(Selection::parse("5-3").is_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<Self> {
+        if s.trim() == "all" {
+            return Ok(Selection::All);
+        }
+
+        s.split(',')
+            .map(|part| {
+                let part = part.trim();
+                let (start, end) = match part.find('-') {
+                    Some(idx) => (part[..idx].parse()?, part[idx + 1..].parse()?),
+                    None => {
+                        let day = part.parse()?;
+                        (day, day)
+                    }
+                };
+
+                if start > end {
+                    return Err(crate::error::AocError::UsageError(format!(
+                        "invalid range of days: {}",
+                        part
+                    )));
+                }
+
+                Ok((start, end))
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(Selection::Days)
+    }
+
+    pub fn contains(&self, day: usize) -> bool {
+        match self {
+            Selection::All => true,
+            Selection::Days(ranges) => ranges.iter().any(|(s, e)| *s <= day && day <= *e),
+        }
+    }
+
+    /// Combines this selection with another.
+    pub fn union(self, other: Self) -> Self {
+        match (self, other) {
+            (Selection::Days(mut a), Selection::Days(b)) => {
+                a.extend(b);
+                Selection::Days(a)
+            }
+            _ => Selection::All,
+        }
+    }
+}
@@ -1,16 +1,20 @@
-use serde::Serialize;
-use serde_json;
+use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::{
     convert::TryFrom,
-    env,
-    fmt::{Debug, Display},
+    fmt::{self, Debug, Display},
+    time::{Duration, Instant},
 };
 
+use crate::error::{self, AocError};
+#[allow(deprecated)]
 use crate::load_input;
+use crate::output::{plain, OutputFormat};
+use crate::util::{load_raw_input, to_lines};
 
-/// This struct enables printing a given solution in either plaintext or JSON,
-/// depending on the presence of the `AOC_OUTPUT_JSON` ENV var. Its main purpose
-/// is to standardize the output for consuption by the CI system.
+/// This struct enables printing a given solution as plaintext via `Display`,
+/// or in any [OutputFormat] via [Solution::format], such as the one selected
+/// by the `AOC_OUTPUT_JSON` ENV var with [OutputFormat::from_env]. Its main
+/// purpose is to standardize the output for consuption by the CI system.
 ///
 /// # Usage
 ///
@@ -29,7 +33,12 @@ use crate::load_input;
 ///     "{\"part_one\":\"hello world\",\"part_two\":12345}".to_string()
 /// );
 /// ```
-#[derive(Debug, Serialize, PartialEq)]
+///
+/// A `Solution` may optionally carry [Timings], as produced by
+/// [TrySolver::solve_timed] and [Solver::solve_timed]. Timings are only
+/// included in the output when present, and are ignored when comparing
+/// solutions.
+#[derive(Debug, Serialize)]
 #[deprecated]
 pub struct Solution<T, G>
 where
@@ -38,6 +47,19 @@ where
 {
     pub part_one: T,
     pub part_two: G,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timings: Option<Timings>,
+}
+
+#[allow(deprecated)]
+impl<T, G> PartialEq for Solution<T, G>
+where
+    T: Display + Serialize + PartialEq,
+    G: Display + Serialize + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.part_one == other.part_one && self.part_two == other.part_two
+    }
 }
 
 /// The default implementation of `Solution` is as follows:
@@ -48,43 +70,95 @@ where
 ///
 /// assert_eq!(default, expected);
 /// ```
+#[allow(deprecated)]
 impl Default for Solution<&str, &str> {
     fn default() -> Self {
         Solution::new("not implemented", "not implemented")
     }
 }
 
+#[allow(deprecated)]
 impl<T, G> Solution<T, G>
 where
     T: Display + Serialize + PartialEq,
     G: Display + Serialize + PartialEq,
 {
     pub fn new(part_one: T, part_two: G) -> Self {
-        Self { part_one, part_two }
+        Self {
+            part_one,
+            part_two,
+            timings: None,
+        }
+    }
+
+    /// Attaches the given [Timings] to this solution.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use aoc_helpers::solution::{Solution, Timings};
+    ///
+    /// let timings = Timings::new(
+    ///     Duration::from_micros(250),
+    ///     Duration::from_millis(3),
+    ///     Duration::from_nanos(1500),
+    /// );
+    /// let s = Solution::new(1, 2).with_timings(timings);
+    ///
+    /// assert_eq!(
+    ///     s.to_string(),
+    ///     "part 1: 1\npart 2: 2\ntimings: parse 250.00µs, part 1 3.00ms, part 2 1.50µs, total 3.25ms"
+    /// );
+    /// assert_eq!(
+    ///     serde_json::to_string(&s).unwrap(),
+    ///     concat!(
+    ///         "{\"part_one\":1,\"part_two\":2,\"timings\":",
+    ///         "{\"parse_ns\":250000,\"part_one_ns\":3000000,\"part_two_ns\":1500,\"total_ns\":3251500}}"
+    ///     )
+    /// );
+    /// assert_eq!(s.timings(), Some(&timings));
+    /// assert_eq!(s, Solution::new(1, 2));
+    /// ```
+    pub fn with_timings(mut self, timings: Timings) -> Self {
+        self.timings = Some(timings);
+        self
+    }
+
+    /// The [Timings] attached to this solution, if any.
+    pub fn timings(&self) -> Option<&Timings> {
+        self.timings.as_ref()
+    }
+
+    /// Formats this solution in the given [OutputFormat].
+    ///
+    /// ```
+    /// use aoc_helpers::output::OutputFormat;
+    /// use aoc_helpers::Solution;
+    ///
+    /// let s = Solution::new(1, "two");
+    /// assert_eq!(
+    ///     s.format(OutputFormat::Markdown).unwrap(),
+    ///     "| part 1 | part 2 |\n| --- | --- |\n| 1 | two |"
+    /// );
+    /// ```
+    pub fn format(&self, format: OutputFormat) -> error::Result<String> {
+        format.format_solution(self)
     }
 }
 
+#[allow(deprecated)]
 impl<T, G> Display for Solution<T, G>
 where
     T: Display + Serialize + PartialEq,
     G: Display + Serialize + PartialEq,
 {
+    /// Formats this solution as plaintext, which never fails. Use
+    /// [Solution::format] to choose another format.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if env::var("AOC_OUTPUT_JSON").is_ok() {
-            // So it's probably not idiomatic to panic here, but, I want this
-            // specific behavior in this specific case. I would not do this in
-            // code destined for production
-            write!(
-                f,
-                "{}",
-                serde_json::to_string(&self).expect("unable to convert self to json")
-            )
-        } else {
-            write!(f, "part 1: {}\npart 2: {}", self.part_one, self.part_two)
-        }
+        write!(f, "{}", plain(self))
     }
 }
 
+#[allow(deprecated)]
 impl<T, G> From<(T, G)> for Solution<T, G>
 where
     T: Display + Serialize + PartialEq,
@@ -95,6 +169,67 @@ where
     }
 }
 
+/// The time taken to parse the input and to solve each part of a puzzle.
+///
+/// When serialized, each duration is represented in whole nanoseconds.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+impl Timings {
+    pub fn new(parse: Duration, part_one: Duration, part_two: Duration) -> Self {
+        Self {
+            parse,
+            part_one,
+            part_two,
+        }
+    }
+
+    /// The combined time taken by all of the phases.
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one + self.part_two
+    }
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "parse {:.2?}, part 1 {:.2?}, part 2 {:.2?}, total {:.2?}",
+            self.parse,
+            self.part_one,
+            self.part_two,
+            self.total()
+        )
+    }
+}
+
+impl Serialize for Timings {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Timings", 4)?;
+        state.serialize_field("parse_ns", &nanos(self.parse))?;
+        state.serialize_field("part_one_ns", &nanos(self.part_one))?;
+        state.serialize_field("part_two_ns", &nanos(self.part_two))?;
+        state.serialize_field("total_ns", &nanos(self.total()))?;
+        state.end()
+    }
+}
+
+/// Returns the given duration in whole nanoseconds, saturating at `u64::MAX`.
+pub(crate) fn nanos(d: Duration) -> u64 {
+    u64::try_from(d.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// Runs `f`, returning its result along with the time it took.
+fn timed<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let start = Instant::now();
+    let res = f();
+    (res, start.elapsed())
+}
+
 /// This trait is intended to enforce a standard interface for solutions such
 /// that they are easier to consume by various components (examples, tests,
 /// benchmarks, etc.).
@@ -142,6 +277,7 @@ where
 
     /// Attempts to load input based on the DAY of this solver. This function
     /// can panic!
+    #[allow(deprecated)]
     fn load_input() -> Vec<String> {
         let day = <Self as Solver>::solver_day();
         load_input(&day).expect("could not load input")
@@ -159,14 +295,432 @@ where
     /// if the implementor cannot be constructed from the input. This may panic
     /// in the event the `part_one` or `part_two` implementations panic.
     /// Basically, you have to assume this function can panic!
+    #[allow(deprecated)]
     fn solve() -> Solution<Self::P1, Self::P2> {
         let mut solver = <Self as Solver>::instance();
 
         Solution::new(solver.part_one(), solver.part_two())
     }
+
+    /// Like [solve](Solver::solve), but also measures the time taken to
+    /// construct the solver and to solve each part, excluding the time taken
+    /// to load the input. This function can panic for the same reasons.
+    #[allow(deprecated)]
+    fn solve_timed() -> Solution<Self::P1, Self::P2> {
+        let input = <Self as Solver>::load_input();
+        let (solver, parse) = timed(|| Self::try_from(input));
+        let mut solver = solver.expect("could not parse input");
+        let (part_one, part_one_time) = timed(|| solver.part_one());
+        let (part_two, part_two_time) = timed(|| solver.part_two());
+
+        Solution::new(part_one, part_two).with_timings(Timings::new(
+            parse,
+            part_one_time,
+            part_two_time,
+        ))
+    }
+}
+
+/// The phase of a [TrySolver] in which a failure occurred.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Stage {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::PartOne => write!(f, "part one"),
+            Stage::PartTwo => write!(f, "part two"),
+        }
+    }
+}
+
+/// A fallible counterpart to [Solver]. Parsing and both parts return a
+/// [Result](std::result::Result), and any failure is reported as an
+/// [AocError::SolverError] identifying the solver and the [Stage] that failed,
+/// rather than panicking.
+///
+/// Solvers are constructed from the entire input as a `&str`, which they may
+/// borrow from for the lifetime `'a`. Because of this, the caller owns the
+/// input: load it with [load_input](TrySolver::load_input), then pass it to
+/// [solve_from](TrySolver::solve_from). Existing line-based [Solver]
+/// implementations can be used wherever a `TrySolver` is expected via the
+/// [Lines] adapter.
+///
+/// # Usage
+///
+/// ```
+/// use aoc_helpers::solution::{Stage, TrySolver};
+/// use aoc_helpers::error::AocError;
+///
+/// struct Longest<'a>(Vec<&'a str>);
+///
+/// impl<'a> TrySolver<'a> for Longest<'a> {
+///     const ID: &'static str = "longest words";
+///     const DAY: usize = 1;
+///
+///     type P1 = &'a str;
+///     type P2 = usize;
+///     type Error = String;
+///
+///     fn parse(input: &'a str) -> Result<Self, Self::Error> {
+///         Ok(Longest(input.split_whitespace().collect()))
+///     }
+///
+///     fn part_one(&mut self) -> Result<Self::P1, Self::Error> {
+///         self.0
+///             .iter()
+///             .copied()
+///             .max_by_key(|w| w.len())
+///             .ok_or_else(|| "no words".to_string())
+///     }
+///
+///     fn part_two(&mut self) -> Result<Self::P2, Self::Error> {
+///         Ok(self.0.len())
+///     }
+/// }
+///
+/// let input = String::from("a bbb cc");
+/// let solution = Longest::solve_from(&input).unwrap();
+/// assert_eq!(solution.to_string(), "part 1: bbb\npart 2: 3");
+///
+/// match Longest::solve_from("") {
+///     Err(AocError::SolverError { label, stage, reason }) => {
+///         assert_eq!(label, "001 longest words");
+///         assert_eq!(stage, Stage::PartOne);
+///         assert_eq!(reason, "no words");
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// // loads the input for day 1, or the file given by `AOC_INPUT`
+/// # let path = std::env::temp_dir().join("aoc_helpers_try_solver_doc");
+/// # std::fs::write(&path, "dd a eee").unwrap();
+/// # std::env::set_var("AOC_INPUT", &path);
+/// let input = Longest::load_input().unwrap();
+/// let solution = Longest::solve_from(&input).unwrap();
+/// assert_eq!(solution.to_string(), "part 1: eee\npart 2: 3");
+/// ```
+pub trait TrySolver<'a>: Sized {
+    /// The title of this puzzle. Used for displaying in benchmarks and whatnot
+    const ID: &'static str;
+
+    /// The numerical day associated with this puzzle. Used for input loading
+    /// and labeling.
+    const DAY: usize;
+
+    /// The type of the solution for part one
+    type P1: Display + Serialize + PartialEq;
+
+    /// The type of the solution for part two
+    type P2: Display + Serialize + PartialEq;
+
+    /// The type of error produced when parsing or either part fails
+    type Error: Display;
+
+    /// Attempts to construct this solver from the entire input.
+    fn parse(input: &'a str) -> Result<Self, Self::Error>;
+
+    /// Attempts to produce the solution for part one.
+    fn part_one(&mut self) -> Result<Self::P1, Self::Error>;
+
+    /// Attempts to produce the solution for part two.
+    fn part_two(&mut self) -> Result<Self::P2, Self::Error>;
+
+    /// Returns a complete label for this puzzle in the form `001 my puzzle id`
+    fn solver_label() -> String {
+        format!(
+            "{} {}",
+            <Self as TrySolver>::solver_day(),
+            <Self as TrySolver>::ID
+        )
+    }
+
+    /// Returns the [String] representation of the day, zero-padded to len 3
+    fn solver_day() -> String {
+        format!("{:03}", <Self as TrySolver>::DAY)
+    }
+
+    /// Attempts to load the entire input based on the DAY of this solver.
+    /// Solvers may borrow from the input, so the caller keeps it and passes
+    /// it to [instance_from](TrySolver::instance_from) or
+    /// [solve_from](TrySolver::solve_from).
+    fn load_input() -> error::Result<String> {
+        load_raw_input(&<Self as TrySolver>::solver_day())
+    }
+
+    /// Attempts to construct an instance of this solver from the given input.
+    fn instance_from(input: &'a str) -> error::Result<Self> {
+        Self::parse(input).map_err(|e| solver_error::<Self>(Stage::Parse, e))
+    }
+
+    /// Attempts to produce the combined part one and two [Solution] for the
+    /// given input.
+    #[allow(deprecated)]
+    fn solve_from(input: &'a str) -> error::Result<Solution<Self::P1, Self::P2>> {
+        let mut solver = Self::instance_from(input)?;
+        let part_one = TrySolver::part_one(&mut solver)
+            .map_err(|e| solver_error::<Self>(Stage::PartOne, e))?;
+        let part_two = TrySolver::part_two(&mut solver)
+            .map_err(|e| solver_error::<Self>(Stage::PartTwo, e))?;
+
+        Ok(Solution::new(part_one, part_two))
+    }
+
+    /// Like [solve_from](TrySolver::solve_from), but also measures the time
+    /// taken to parse the input and to solve each part, attaching the
+    /// resulting [Timings] to the [Solution].
+    #[allow(deprecated)]
+    fn solve_timed(input: &'a str) -> error::Result<Solution<Self::P1, Self::P2>> {
+        let (solver, parse) = timed(|| Self::instance_from(input));
+        let mut solver = solver?;
+        let (part_one, part_one_time) = timed(|| TrySolver::part_one(&mut solver));
+        let part_one = part_one.map_err(|e| solver_error::<Self>(Stage::PartOne, e))?;
+        let (part_two, part_two_time) = timed(|| TrySolver::part_two(&mut solver));
+        let part_two = part_two.map_err(|e| solver_error::<Self>(Stage::PartTwo, e))?;
+
+        Ok(Solution::new(part_one, part_two).with_timings(Timings::new(
+            parse,
+            part_one_time,
+            part_two_time,
+        )))
+    }
+}
+
+/// Wraps an error produced by the solver `S` during `stage`.
+fn solver_error<'a, S: TrySolver<'a>>(stage: Stage, err: S::Error) -> AocError {
+    AocError::SolverError {
+        label: S::solver_label(),
+        stage,
+        reason: err.to_string(),
+    }
+}
+
+/// Adapts a line-based [Solver] into a [TrySolver], splitting the input into
+/// lines before handing it to the wrapped solver. Failures to construct the
+/// wrapped solver are reported using the `Debug` representation of its error.
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use aoc_helpers::solution::{Lines, Solution, Solver, TrySolver};
+///
+/// struct Count(usize);
+///
+/// impl TryFrom<Vec<String>> for Count {
+///     type Error = ();
+///
+///     fn try_from(input: Vec<String>) -> Result<Self, Self::Error> {
+///         Ok(Count(input.len()))
+///     }
+/// }
+///
+/// impl Solver for Count {
+///     const ID: &'static str = "line count";
+///     const DAY: usize = 3;
+///
+///     type P1 = usize;
+///     type P2 = usize;
+///
+///     fn part_one(&mut self) -> Self::P1 {
+///         self.0
+///     }
+///
+///     fn part_two(&mut self) -> Self::P2 {
+///         self.0 * 2
+///     }
+/// }
+///
+/// assert_eq!(Lines::<Count>::solver_label(), "003 line count");
+/// assert_eq!(Lines::<Count>::solve_from("a\nb\nc").unwrap(), Solution::new(3, 6));
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Lines<S>(pub S);
+
+#[allow(deprecated)]
+impl<'a, S> TrySolver<'a> for Lines<S>
+where
+    S: Solver,
+    <S as TryFrom<Vec<String>>>::Error: Debug,
+{
+    #[allow(deprecated)]
+    const ID: &'static str = <S as Solver>::ID;
+    #[allow(deprecated)]
+    const DAY: usize = <S as Solver>::DAY;
+
+    #[allow(deprecated)]
+    type P1 = <S as Solver>::P1;
+    #[allow(deprecated)]
+    type P2 = <S as Solver>::P2;
+    type Error = String;
+
+    fn parse(input: &'a str) -> Result<Self, Self::Error> {
+        S::try_from(to_lines(input))
+            .map(Lines)
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[allow(deprecated)]
+    fn part_one(&mut self) -> Result<Self::P1, Self::Error> {
+        Ok(Solver::part_one(&mut self.0))
+    }
+
+    #[allow(deprecated)]
+    fn part_two(&mut self) -> Result<Self::P2, Self::Error> {
+        Ok(Solver::part_two(&mut self.0))
+    }
+}
+
+/// A solver whose parsed input is an immutable model shared by both parts,
+/// for puzzles where both parts depend on the same expensive preprocessing.
+///
+/// Rather than coupling the parts through mutable state on the solver, part
+/// one returns its answer along with a [Carry](SharedSolver::Carry) which is
+/// handed to part two. Days that have nothing to pass along use `()`.
+/// Implementors are usually unit structs, and are used wherever a [TrySolver]
+/// is expected via the [Shared] adapter, which times parsing and each part
+/// separately, so that the cost of preprocessing is attributed to the parse
+/// phase and work done in part one is not counted again in part two.
+///
+/// # Usage
+///
+/// ```
+/// use aoc_helpers::solution::{Shared, SharedSolver, Solution, TrySolver};
+///
+/// struct Words;
+///
+/// impl<'a> SharedSolver<'a> for Words {
+///     const ID: &'static str = "words";
+///     const DAY: usize = 4;
+///
+///     type Model = Vec<&'a str>;
+///     type Carry = usize;
+///     type P1 = usize;
+///     type P2 = String;
+///     type Error = String;
+///
+///     fn parse(input: &'a str) -> Result<Self::Model, Self::Error> {
+///         Ok(input.split_whitespace().collect())
+///     }
+///
+///     fn part_one(model: &Self::Model) -> Result<(Self::P1, Self::Carry), Self::Error> {
+///         let longest = model.iter().map(|w| w.len()).max().ok_or("no words")?;
+///         Ok((longest, longest))
+///     }
+///
+///     fn part_two(model: &Self::Model, longest: Self::Carry) -> Result<Self::P2, Self::Error> {
+///         Ok(model.iter().filter(|w| w.len() == longest).copied().collect())
+///     }
+/// }
+///
+/// assert_eq!(Shared::<Words>::solver_label(), "004 words");
+/// assert_eq!(
+///     Shared::<Words>::solve_from("ab c de f").unwrap(),
+///     Solution::new(2, "abde".to_string())
+/// );
+///
+/// let registry = aoc_helpers::aoc_registry![Shared<Words>];
+/// assert_eq!(registry.labels(), vec!["004 words"]);
+/// ```
+pub trait SharedSolver<'a> {
+    /// The title of this puzzle. Used for displaying in benchmarks and whatnot
+    const ID: &'static str;
+
+    /// The numerical day associated with this puzzle. Used for input loading
+    /// and labeling.
+    const DAY: usize;
+
+    /// The parsed representation of the input, shared by both parts
+    type Model;
+
+    /// Intermediate results produced by part one for use in part two
+    type Carry;
+
+    /// The type of the solution for part one
+    type P1: Display + Serialize + PartialEq;
+
+    /// The type of the solution for part two
+    type P2: Display + Serialize + PartialEq;
+
+    /// The type of error produced when parsing or either part fails
+    type Error: Display;
+
+    /// Attempts to construct the shared model from the entire input.
+    fn parse(input: &'a str) -> Result<Self::Model, Self::Error>;
+
+    /// Attempts to produce the solution for part one, along with anything
+    /// part two needs from it.
+    fn part_one(model: &Self::Model) -> Result<(Self::P1, Self::Carry), Self::Error>;
+
+    /// Attempts to produce the solution for part two.
+    fn part_two(model: &Self::Model, carry: Self::Carry) -> Result<Self::P2, Self::Error>;
+}
+
+/// Adapts a [SharedSolver] into a [TrySolver]. Part two consumes the carry
+/// produced by part one, so it fails with [SharedError::MissingCarry] unless
+/// part one has been solved first, rather than quietly repeating that work.
+pub struct Shared<'a, S: SharedSolver<'a>> {
+    model: S::Model,
+    carry: Option<S::Carry>,
+}
+
+impl<'a, S: SharedSolver<'a>> Shared<'a, S> {
+    /// The shared model parsed from the input.
+    pub fn model(&self) -> &S::Model {
+        &self.model
+    }
+}
+
+impl<'a, S: SharedSolver<'a>> TrySolver<'a> for Shared<'a, S> {
+    const ID: &'static str = S::ID;
+    const DAY: usize = S::DAY;
+
+    type P1 = S::P1;
+    type P2 = S::P2;
+    type Error = SharedError<S::Error>;
+
+    fn parse(input: &'a str) -> Result<Self, Self::Error> {
+        Ok(Shared {
+            model: S::parse(input).map_err(SharedError::Solver)?,
+            carry: None,
+        })
+    }
+
+    fn part_one(&mut self) -> Result<Self::P1, Self::Error> {
+        let (answer, carry) = S::part_one(&self.model).map_err(SharedError::Solver)?;
+        self.carry = Some(carry);
+        Ok(answer)
+    }
+
+    fn part_two(&mut self) -> Result<Self::P2, Self::Error> {
+        let carry = self.carry.take().ok_or(SharedError::MissingCarry)?;
+        S::part_two(&self.model, carry).map_err(SharedError::Solver)
+    }
+}
+
+/// The error produced by a [Shared] solver.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SharedError<E> {
+    /// The [SharedSolver] itself failed
+    Solver(E),
+    /// Part two was requested without first solving part one
+    MissingCarry,
+}
+
+impl<E: Display> Display for SharedError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SharedError::Solver(e) => e.fmt(f),
+            SharedError::MissingCarry => write!(f, "part one must be solved before part two"),
+        }
+    }
 }
 
 #[cfg(test)]
+#[allow(deprecated)]
 mod tests {
     mod solver {
         use crate::error::AocError;
@@ -206,4 +760,193 @@ mod tests {
             assert_eq!(Foo::solver_label(), String::from("001 A name"));
         }
     }
+
+    mod try_solver {
+        use crate::error::AocError;
+
+        use super::super::*;
+
+        #[derive(Debug, Clone, Default)]
+        pub struct Bar {
+            values: Vec<u32>,
+        }
+
+        impl TrySolver<'_> for Bar {
+            const ID: &'static str = "Another name";
+            const DAY: usize = 12;
+
+            type P1 = u32;
+            type P2 = u32;
+            type Error = String;
+
+            fn parse(input: &str) -> Result<Self, Self::Error> {
+                let values = input
+                    .lines()
+                    .map(|l| l.parse().map_err(|_| format!("invalid value '{}'", l)))
+                    .collect::<Result<_, _>>()?;
+                Ok(Bar { values })
+            }
+
+            fn part_one(&mut self) -> Result<Self::P1, Self::Error> {
+                Ok(self.values.iter().sum())
+            }
+
+            fn part_two(&mut self) -> Result<Self::P2, Self::Error> {
+                self.values
+                    .iter()
+                    .try_fold(1_u32, |acc, v| acc.checked_mul(*v))
+                    .ok_or_else(|| "product overflowed".to_string())
+            }
+        }
+
+        #[test]
+        fn basics() {
+            assert_eq!(Bar::solver_day(), String::from("012"));
+            assert_eq!(Bar::solver_label(), String::from("012 Another name"));
+        }
+
+        #[test]
+        fn solving() {
+            let solution = Bar::solve_from("2\n3\n4\n").unwrap();
+            assert_eq!(solution, Solution::new(9, 24));
+        }
+
+        #[test]
+        fn failures() {
+            match Bar::solve_from("2\nx") {
+                Err(AocError::SolverError {
+                    label,
+                    stage,
+                    reason,
+                }) => {
+                    assert_eq!(label, "012 Another name");
+                    assert_eq!(stage, Stage::Parse);
+                    assert_eq!(reason, "invalid value 'x'");
+                }
+                other => panic!("unexpected result: {:?}", other),
+            }
+
+            let err = Bar::solve_from("100000\n100000").unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "012 Another name failed during part two: product overflowed"
+            );
+        }
+
+        #[derive(Debug, Clone, Default)]
+        pub struct Borrowed<'a> {
+            words: Vec<&'a str>,
+        }
+
+        impl<'a> TrySolver<'a> for Borrowed<'a> {
+            const ID: &'static str = "Borrowed";
+            const DAY: usize = 2;
+
+            type P1 = &'a str;
+            type P2 = String;
+            type Error = String;
+
+            fn parse(input: &'a str) -> Result<Self, Self::Error> {
+                Ok(Borrowed {
+                    words: input.split(',').collect(),
+                })
+            }
+
+            fn part_one(&mut self) -> Result<Self::P1, Self::Error> {
+                self.words.first().copied().ok_or_else(|| "empty".into())
+            }
+
+            fn part_two(&mut self) -> Result<Self::P2, Self::Error> {
+                Ok(self.words.concat())
+            }
+        }
+
+        #[test]
+        fn borrowing() {
+            let input = String::from("foo,bar,baz");
+            let solution = Borrowed::solve_from(&input).unwrap();
+            assert_eq!(solution, Solution::new("foo", "foobarbaz".to_string()));
+        }
+
+        #[test]
+        fn adapting() {
+            use super::solver::Foo;
+
+            assert_eq!(Lines::<Foo>::solver_label(), "001 A name");
+            assert_eq!(
+                Lines::<Foo>::solve_from("a\nb").unwrap(),
+                Solution::new(0, 1)
+            );
+        }
+
+        pub struct Counted;
+
+        impl<'a> SharedSolver<'a> for Counted {
+            const ID: &'static str = "Counted";
+            const DAY: usize = 7;
+
+            type Model = Vec<&'a str>;
+            type Carry = usize;
+            type P1 = usize;
+            type P2 = usize;
+            type Error = String;
+
+            fn parse(input: &'a str) -> Result<Self::Model, Self::Error> {
+                Ok(input.lines().collect())
+            }
+
+            fn part_one(model: &Self::Model) -> Result<(Self::P1, Self::Carry), Self::Error> {
+                Ok((model.len(), model.len()))
+            }
+
+            fn part_two(model: &Self::Model, carry: Self::Carry) -> Result<Self::P2, Self::Error> {
+                model
+                    .first()
+                    .map(|l| l.len() + carry)
+                    .ok_or_else(|| "empty".into())
+            }
+        }
+
+        #[test]
+        fn sharing() {
+            assert_eq!(Shared::<Counted>::solver_label(), "007 Counted");
+            let solution = Shared::<Counted>::solve_timed("abc\nd").unwrap();
+            assert_eq!((solution.part_one, solution.part_two), (2, 5));
+            assert!(solution.timings().is_some());
+
+            let mut solver = Shared::<Counted>::instance_from("abc").unwrap();
+            assert_eq!(solver.model(), &vec!["abc"]);
+            assert_eq!(
+                TrySolver::part_two(&mut solver),
+                Err(SharedError::MissingCarry)
+            );
+            assert_eq!(TrySolver::part_one(&mut solver), Ok(1));
+            assert_eq!(TrySolver::part_two(&mut solver), Ok(4));
+
+            assert_eq!(
+                Shared::<Counted>::solve_from("").unwrap_err().to_string(),
+                "007 Counted failed during part two: empty"
+            );
+        }
+
+        #[test]
+        fn timing() {
+            let solution = Bar::solve_timed("2\n3\n4\n").unwrap();
+            let timings = *solution.timings().expect("missing timings");
+            assert_eq!(
+                timings.total(),
+                timings.parse + timings.part_one + timings.part_two
+            );
+            assert_eq!((solution.part_one, solution.part_two), (9, 24));
+            assert_eq!(solution, Bar::solve_from("2\n3\n4\n").unwrap());
+
+            let json = serde_json::to_value(Bar::solve_timed("1").unwrap()).unwrap();
+            assert!(json["timings"]["total_ns"].is_u64());
+
+            let json = serde_json::to_value(Bar::solve_from("1").unwrap()).unwrap();
+            assert!(json.get("timings").is_none());
+
+            assert!(Bar::solve_timed("x").is_err());
+        }
+    }
 }
@@ -1,10 +1,10 @@
 use std::env;
-use std::fs::{self, File};
-use std::io::{BufRead, BufReader};
+use std::fs;
 use std::path::Path;
 use std::str::FromStr;
 
 use crate::error::{AocError, Result};
+use crate::input::InputResolver;
 
 #[macro_export]
 #[deprecated]
@@ -89,6 +89,98 @@ macro_rules! aoc_benches {
     };
 }
 
+/// Generates a test for each of the given examples of a
+/// [TrySolver](crate::TrySolver), checking the answers to part one and/or part
+/// two. Either answer may be omitted to skip checking that part.
+///
+/// Inline examples are given with `input`, and are normalized with
+/// [example_input], so they may be indented to match the surrounding code.
+/// Examples stored alongside the real input for the day (`examples/001_foo/`)
+/// are given with `file`, and are loaded via [load_named_raw_input].
+///
+/// Line-based [Solver](crate::Solver) implementations can be tested via the
+/// [Lines](crate::solution::Lines) adapter. Both parts are run on the same
+/// solver, so examples for a [Shared](crate::solution::Shared) solver must
+/// check part one whenever they check part two.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///     use aoc_helpers::aoc_tests;
+///
+///     aoc_tests! {
+///         Day001,
+///         example: {
+///             input: "
+///                 1000
+///                 2000
+///             ",
+///             part_one: 3000,
+///             part_two: "2000",
+///         },
+///         part_one_only: { input: "1", part_one: 1 },
+///         larger_example: { file: "example2", part_two: 45000 },
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! aoc_tests {
+    (
+        $solver:ty,
+        $(
+            $name:ident: {
+                $kind:ident: $input:expr
+                $(, part_one: $part_one:expr)?
+                $(, part_two: $part_two:expr)?
+                $(,)?
+            }
+        ),+
+        $(,)?
+    ) => {
+        $(
+            #[test]
+            fn $name() {
+                #[allow(unused_imports)]
+                use $crate::TrySolver;
+
+                let input = $crate::aoc_tests!(@input $solver, $kind, $input);
+                #[allow(unused_mut, unused_variables)]
+                let mut solver = <$solver as $crate::TrySolver>::instance_from(&input)
+                    .expect("could not parse example");
+                $(
+                    assert_eq!(
+                        <$solver as $crate::TrySolver>::part_one(&mut solver)
+                            .unwrap_or_else(|e| panic!("part one failed: {}", e)),
+                        $part_one,
+                        "part one"
+                    );
+                )?
+                $(
+                    assert_eq!(
+                        <$solver as $crate::TrySolver>::part_two(&mut solver)
+                            .unwrap_or_else(|e| panic!("part two failed: {}", e)),
+                        $part_two,
+                        "part two"
+                    );
+                )?
+            }
+        )+
+    };
+    (@input $solver:ty, input, $input:expr) => {
+        $crate::util::example_input($input)
+    };
+    (@input $solver:ty, file, $input:expr) => {
+        $crate::util::load_named_raw_input(
+            &<$solver as $crate::TrySolver>::solver_day(),
+            $input,
+        )
+        .expect("could not load example")
+    };
+}
+
 /// Will attempt to load input from the specified `AOC_INPUT` file, otherwise
 /// will default to loading the corresponding input file for the day given by
 /// `default_day`.
@@ -98,55 +190,79 @@ macro_rules! aoc_benches {
 /// let lines: Vec<String> = load_input("002").expect("could not load input");
 /// ```
 #[deprecated]
+#[allow(deprecated)]
 pub fn load_input(default_day: &str) -> Result<Vec<String>> {
-    //
-    // examples/003_toboggan-trajectory/input
-    //
-    load_external_input("AOC_INPUT").or_else(|e| {
+    load_raw_input(default_day).map(|input| to_lines(&input))
+}
+
+/// Like [load_input], but returns the entire contents of the input as a single
+/// [String] rather than splitting it into lines. Unless `AOC_INPUT` is set, the
+/// input is found by the [InputResolver] configured from the environment, as
+/// described by [InputResolver::load_day].
+///
+/// ```no_run
+/// use aoc_helpers::util::load_raw_input;
+/// let input: String = load_raw_input("002").expect("could not load input");
+/// ```
+pub fn load_raw_input(default_day: &str) -> Result<String> {
+    load_external_raw_input("AOC_INPUT").or_else(|e| {
         // If we errored because the var was not set, just return the
         // the default. Otherwise, we want to propagate the error because
         // it means that the var *was* set but we couldn't open/load the
         // file.
         match e {
-            AocError::VarError(_) => load_named_input(default_day, "input"),
+            AocError::VarError(_) => {
+                let resolver = InputResolver::from_env()?;
+                resolver.load_day(default_day, &resolver.input_name)
+            }
             _ => Err(e),
         }
     })
 }
 
 #[deprecated]
+#[allow(deprecated)]
 pub fn load_named_input(day: &str, name: &str) -> Result<Vec<String>> {
-    //
-    // examples/003_toboggan-trajectory/<name>
-    //
-    let examples_dir = Path::new("examples");
-    for entry in fs::read_dir(examples_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() && entry.file_name().into_string()?.starts_with(day) {
-            if let Some(file) = path.join(name).to_str() {
-                return load_lines(file);
-            }
-        }
-    }
+    load_named_raw_input(day, name).map(|input| to_lines(&input))
+}
 
-    Err(AocError::InputMissing(format!("{}: '{}'", day, name)))
+/// Loads the input with the given name for the given zero-padded day, such as
+/// `examples/003_toboggan-trajectory/<name>`, via the [InputResolver]
+/// configured from the environment, as described by [InputResolver::load_day].
+pub fn load_named_raw_input(day: &str, name: &str) -> Result<String> {
+    InputResolver::from_env()?.load_day(day, name)
 }
 
 #[deprecated]
+#[allow(deprecated)]
 pub fn load_external_input(key: &str) -> Result<Vec<String>> {
+    load_external_raw_input(key).map(|input| to_lines(&input))
+}
+
+pub fn load_external_raw_input(key: &str) -> Result<String> {
     let path = env::var(key)?;
-    load_lines(&path)
+    load_raw(&path)
 }
 
 #[deprecated]
 pub fn load_lines(file: &str) -> Result<Vec<String>> {
-    let mut lines = Vec::new();
-    for line in BufReader::new(File::open(Path::new(file))?).lines() {
-        lines.push(line?);
-    }
+    load_raw(file).map(|input| to_lines(&input))
+}
 
-    Ok(lines)
+/// Reads the entire contents of the given file.
+pub fn load_raw(file: &str) -> Result<String> {
+    Ok(fs::read_to_string(Path::new(file))?)
+}
+
+/// Splits the given input into owned lines, for consumers expecting the
+/// `Vec<String>` form of the input.
+///
+/// ```
+/// use aoc_helpers::util::to_lines;
+/// assert_eq!(to_lines("a\r\nb\n\nc\n"), vec!["a", "b", "", "c"]);
+/// ```
+pub fn to_lines(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
 }
 
 #[deprecated]
@@ -156,6 +272,29 @@ pub fn parse_input<T: FromStr>(
     input.iter().map(|l| T::from_str(l)).collect()
 }
 
+/// Normalizes an inline example by trimming the example as a whole as well as
+/// each of its lines, so that it may be indented to match the surrounding
+/// code. This is the `&str` counterpart to [test_input].
+///
+/// ```
+/// use aoc_helpers::util::example_input;
+///
+/// let example = "
+///     abc
+///
+///     def
+/// ";
+/// assert_eq!(example_input(example), "abc\n\ndef");
+/// ```
+pub fn example_input(input: &str) -> String {
+    input
+        .trim()
+        .split('\n')
+        .map(|s| s.trim())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 #[deprecated]
 pub fn test_input(input: &str) -> Vec<String> {
     input
@@ -166,6 +305,7 @@ pub fn test_input(input: &str) -> Vec<String> {
 }
 
 #[cfg(test)]
+#[allow(deprecated)]
 mod tests {
     use super::*;
 
@@ -194,4 +334,92 @@ mod tests {
         ";
         assert_eq!(test_input(input), expected);
     }
+
+    mod examples {
+        use crate::solution::{Lines, Solver, TrySolver};
+        use std::convert::TryFrom;
+
+        pub struct Calories(Vec<Vec<u32>>);
+
+        impl TrySolver<'_> for Calories {
+            const ID: &'static str = "calories";
+            const DAY: usize = 1;
+
+            type P1 = u32;
+            type P2 = String;
+            type Error = String;
+
+            fn parse(input: &str) -> std::result::Result<Self, Self::Error> {
+                input
+                    .split("\n\n")
+                    .map(|group| {
+                        group
+                            .lines()
+                            .map(|l| l.parse().map_err(|_| format!("invalid: {}", l)))
+                            .collect()
+                    })
+                    .collect::<std::result::Result<_, _>>()
+                    .map(Calories)
+            }
+
+            fn part_one(&mut self) -> std::result::Result<Self::P1, Self::Error> {
+                self.0
+                    .iter()
+                    .map(|g| g.iter().sum())
+                    .max()
+                    .ok_or_else(|| "no groups".into())
+            }
+
+            fn part_two(&mut self) -> std::result::Result<Self::P2, Self::Error> {
+                Ok(format!("{} groups", self.0.len()))
+            }
+        }
+
+        pub struct Count(usize);
+
+        impl TryFrom<Vec<String>> for Count {
+            type Error = ();
+
+            fn try_from(input: Vec<String>) -> std::result::Result<Self, Self::Error> {
+                Ok(Count(input.len()))
+            }
+        }
+
+        impl Solver for Count {
+            const ID: &'static str = "count";
+            const DAY: usize = 2;
+
+            type P1 = usize;
+            type P2 = usize;
+
+            fn part_one(&mut self) -> Self::P1 {
+                self.0
+            }
+
+            fn part_two(&mut self) -> Self::P2 {
+                panic!("not implemented")
+            }
+        }
+
+        aoc_tests! {
+            Calories,
+            both_parts: {
+                input: "
+                    1000
+                    2000
+
+                    4000
+                ",
+                part_one: 4000,
+                part_two: "2 groups",
+            },
+            part_one_only: { input: "5", part_one: 5 },
+            part_two_only: { input: "1\n\n2\n\n3", part_two: "3 groups" },
+        }
+
+        aoc_tests! {
+            Lines<Count>,
+            adapted: { input: "a\nb\nc", part_one: 3 },
+        }
+    }
 }